@@ -4,7 +4,20 @@ use crate::errors::{
     RmrkError,
 };
 
-use openbrush::contracts::psp34::extensions::enumerable::*;
+use ink::{
+    env::hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    prelude::{
+        string::ToString,
+        vec::Vec,
+    },
+};
+use openbrush::{
+    contracts::psp34::extensions::enumerable::*,
+    traits::AccountId,
+};
 
 #[derive(Debug, scale::Encode, scale::Decode)]
 #[cfg_attr(
@@ -22,6 +35,12 @@ pub trait Increment<T = Self> {
     fn inc(&mut self) -> Result<T>;
 }
 
+/// Generator of token Ids
+pub trait IdGenerator {
+    /// Generate the next Id
+    fn next_id(&mut self) -> Result<Id>;
+}
+
 impl Increment for u32 {
     fn inc(&mut self) -> Result<u32> {
         *self = self
@@ -39,7 +58,7 @@ impl Increment for Id {
             Id::U32(id) => id.checked_add(1).map(Id::U32),
             Id::U64(id) => id.checked_add(1).map(Id::U64),
             Id::U128(id) => id.checked_add(1).map(Id::U128),
-            Id::Bytes(_) => panic!("bytes id increment unsupported"),
+            Id::Bytes(_) => return Err(RmrkError::UnsupportedIdVariant.into()),
         }
         .ok_or(Error::Rmrk(RmrkError::Overflow))?;
         Ok(self.clone())
@@ -57,6 +76,15 @@ impl Counter<u32> {
 }
 
 impl Counter<Id> {
+    /// Create a counter of the same variant as `initial`, starting from its value.
+    /// The first generated Id is the one following `initial`.
+    pub fn new(initial: Id) -> Result<Self> {
+        if let Id::Bytes(_) = initial {
+            return Err(RmrkError::UnsupportedIdVariant.into())
+        }
+        Ok(Counter { value: initial })
+    }
+
     pub fn current(&self) -> Id {
         self.value.clone()
     }
@@ -77,3 +105,73 @@ impl Default for Counter<Id> {
         Counter { value: Id::U64(0) }
     }
 }
+
+impl IdGenerator for Counter<Id> {
+    fn next_id(&mut self) -> Result<Id> {
+        self.next()
+    }
+}
+
+/// Generates `Id::Bytes` from the hash of the collection address and a nonce
+#[derive(Default, Debug, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct HashedIdGenerator {
+    collection: AccountId,
+    nonce: u64,
+}
+
+impl HashedIdGenerator {
+    pub fn new(collection: AccountId) -> Self {
+        HashedIdGenerator {
+            collection,
+            nonce: 0,
+        }
+    }
+}
+
+impl IdGenerator for HashedIdGenerator {
+    fn next_id(&mut self) -> Result<Id> {
+        self.nonce = self
+            .nonce
+            .checked_add(1)
+            .ok_or(Error::Rmrk(RmrkError::Overflow))?;
+        let mut hash = <Blake2x256 as HashOutput>::Type::default();
+        ink::env::hash_encoded::<Blake2x256, _>(&(self.collection, self.nonce), &mut hash);
+        Ok(Id::Bytes(hash.to_vec()))
+    }
+}
+
+/// Generates `Id::Bytes` by appending a sequence number to a prefix, e.g. `badge-1`
+#[derive(Default, Debug, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PrefixedIdGenerator {
+    prefix: Vec<u8>,
+    sequence: u64,
+}
+
+impl PrefixedIdGenerator {
+    pub fn new(prefix: Vec<u8>) -> Self {
+        PrefixedIdGenerator {
+            prefix,
+            sequence: 0,
+        }
+    }
+}
+
+impl IdGenerator for PrefixedIdGenerator {
+    fn next_id(&mut self) -> Result<Id> {
+        self.sequence = self
+            .sequence
+            .checked_add(1)
+            .ok_or(Error::Rmrk(RmrkError::Overflow))?;
+        let mut id = self.prefix.clone();
+        id.extend_from_slice(self.sequence.to_string().as_bytes());
+        Ok(Id::Bytes(id))
+    }
+}
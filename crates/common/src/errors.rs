@@ -68,15 +68,59 @@ impl From<Error> for PSP34Error {
     }
 }
 
+/// Cause of a failed cross contract call
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CallError {
+    /// The callee trapped
+    CalleeTrapped,
+    /// The callee reverted
+    CalleeReverted,
+    /// The callee is not a contract
+    NotCallable,
+    /// Any other environment error
+    Environment,
+    /// The callee could not decode the message input
+    CouldNotReadInput,
+    /// The callee returned an error
+    Callee(PSP34Error),
+}
+
+impl From<ink::env::Error> for CallError {
+    fn from(err: ink::env::Error) -> Self {
+        match err {
+            ink::env::Error::CalleeTrapped => Self::CalleeTrapped,
+            ink::env::Error::CalleeReverted => Self::CalleeReverted,
+            ink::env::Error::NotCallable => Self::NotCallable,
+            _ => Self::Environment,
+        }
+    }
+}
+
+impl From<ink::LangError> for CallError {
+    fn from(_: ink::LangError) -> Self {
+        Self::CouldNotReadInput
+    }
+}
+
+impl From<PSP34Error> for CallError {
+    fn from(err: PSP34Error) -> Self {
+        Self::Callee(err)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum RmrkError {
     AcceptedAssetsMissing,
+    AccountHasNoShares,
     AddingPendingAsset,
     AddingPendingChild,
     AddressNotEquippable,
+    AllowlistQuotaExceeded,
     AlreadyAddedAsset,
     AlreadyAddedChild,
+    AlreadyRevealed,
     AssetHasNoParts,
     AssetIdAlreadyExists,
     AssetIdNotEquippable,
@@ -84,22 +128,58 @@ pub enum RmrkError {
     BadConfig,
     BadMintValue,
     BadPriorityLength,
+    BaseUriFrozen,
     CannotMintZeroTokens,
+    CannotTransferSoulbound,
+    CatalogCallFailed(CallError),
     CatalogNotFoundForAsset,
+    ChildBurnFailed,
+    ChildContractNotApproved,
+    ChildHookFailed(CallError),
     ChildNotFound,
+    ChildNotOwnedByCaller,
+    ChildNotOwnedByParent,
+    ChildQueryFailed(CallError),
+    ChildTransferFailed(CallError),
     CollectionIsFull,
+    EquippableCallFailed(CallError),
     InvalidAssetId,
     InvalidParentId,
     InvalidTokenId,
+    MaxAcceptedChildrenReached,
+    MaxCollectionChildrenReached,
+    MaxNestingDepthReached,
+    MaxPendingChildrenReached,
+    MaxRecursiveBurnsReached,
+    MintPerCallLimitExceeded,
+    MintPerWalletLimitExceeded,
+    NestMintFailed,
+    NestingCycle,
+    NoActiveSalePhase,
+    NoPaymentDue,
+    NotAllowlisted,
+    NotChildSender,
     NotEquipped,
     NotTokenOwner,
     Overflow,
     PartIsNotSlot,
+    PaymentTokenCallFailed,
+    PaymentTransferFailed,
+    PendingChildNotExpired,
+    PublicSaleNotStarted,
+    ReservedSupplyLocked,
+    RevealAlreadyCommitted,
+    RevealCommitmentMismatch,
+    RevealNotCommitted,
+    RootOwnerNotFound,
+    RoyaltyTooHigh,
+    SalePhaseSupplyExceeded,
     SlotAlreadyUsed,
     TargetAssetCannotReceiveSlot,
     UnknownEquippableAsset,
     UnknownPart,
     UnknownPartId,
+    UnsupportedIdVariant,
     UriNotFound,
     WithdrawalFailed,
 }
@@ -108,11 +188,14 @@ impl ToString for RmrkError {
     fn to_string(&self) -> String {
         match self {
             RmrkError::AcceptedAssetsMissing => String::from("AcceptedAssetsMissing"),
+            RmrkError::AccountHasNoShares => String::from("AccountHasNoShares"),
             RmrkError::AddingPendingAsset => String::from("AddingPendingAsset"),
             RmrkError::AddingPendingChild => String::from("AddingPendingChild"),
             RmrkError::AddressNotEquippable => String::from("AddressNotEquippable"),
+            RmrkError::AllowlistQuotaExceeded => String::from("AllowlistQuotaExceeded"),
             RmrkError::AlreadyAddedAsset => String::from("AlreadyAddedAsset"),
             RmrkError::AlreadyAddedChild => String::from("AlreadyAddedChild"),
+            RmrkError::AlreadyRevealed => String::from("AlreadyRevealed"),
             RmrkError::AssetHasNoParts => String::from("AssetHasNoParts"),
             RmrkError::AssetIdAlreadyExists => String::from("AssetIdAlreadyExists"),
             RmrkError::AssetIdNotEquippable => String::from("AssetIdNotEquippable"),
@@ -120,22 +203,58 @@ impl ToString for RmrkError {
             RmrkError::BadConfig => String::from("BadConfig"),
             RmrkError::BadMintValue => String::from("BadMintValue"),
             RmrkError::BadPriorityLength => String::from("BadPriorityLength"),
+            RmrkError::BaseUriFrozen => String::from("BaseUriFrozen"),
             RmrkError::CannotMintZeroTokens => String::from("CannotMintZeroTokens"),
+            RmrkError::CannotTransferSoulbound => String::from("CannotTransferSoulbound"),
+            RmrkError::CatalogCallFailed(_) => String::from("CatalogCallFailed"),
             RmrkError::CatalogNotFoundForAsset => String::from("CatalogNotFoundForAsset"),
+            RmrkError::ChildBurnFailed => String::from("ChildBurnFailed"),
+            RmrkError::ChildContractNotApproved => String::from("ChildContractNotApproved"),
+            RmrkError::ChildHookFailed(_) => String::from("ChildHookFailed"),
             RmrkError::ChildNotFound => String::from("ChildNotFound"),
+            RmrkError::ChildNotOwnedByCaller => String::from("ChildNotOwnedByCaller"),
+            RmrkError::ChildNotOwnedByParent => String::from("ChildNotOwnedByParent"),
+            RmrkError::ChildQueryFailed(_) => String::from("ChildQueryFailed"),
+            RmrkError::ChildTransferFailed(_) => String::from("ChildTransferFailed"),
             RmrkError::CollectionIsFull => String::from("CollectionIsFull"),
+            RmrkError::EquippableCallFailed(_) => String::from("EquippableCallFailed"),
             RmrkError::InvalidAssetId => String::from("InvalidAssetId"),
             RmrkError::InvalidParentId => String::from("InvalidParentId"),
             RmrkError::InvalidTokenId => String::from("InvalidTokenId"),
+            RmrkError::MaxAcceptedChildrenReached => String::from("MaxAcceptedChildrenReached"),
+            RmrkError::MaxCollectionChildrenReached => String::from("MaxCollectionChildrenReached"),
+            RmrkError::MaxNestingDepthReached => String::from("MaxNestingDepthReached"),
+            RmrkError::MaxPendingChildrenReached => String::from("MaxPendingChildrenReached"),
+            RmrkError::MaxRecursiveBurnsReached => String::from("MaxRecursiveBurnsReached"),
+            RmrkError::MintPerCallLimitExceeded => String::from("MintPerCallLimitExceeded"),
+            RmrkError::MintPerWalletLimitExceeded => String::from("MintPerWalletLimitExceeded"),
+            RmrkError::NestMintFailed => String::from("NestMintFailed"),
+            RmrkError::NestingCycle => String::from("NestingCycle"),
+            RmrkError::NoActiveSalePhase => String::from("NoActiveSalePhase"),
+            RmrkError::NoPaymentDue => String::from("NoPaymentDue"),
+            RmrkError::NotAllowlisted => String::from("NotAllowlisted"),
+            RmrkError::NotChildSender => String::from("NotChildSender"),
             RmrkError::NotEquipped => String::from("NotEquipped"),
             RmrkError::NotTokenOwner => String::from("NotTokenOwner"),
             RmrkError::Overflow => String::from("Overflow"),
             RmrkError::PartIsNotSlot => String::from("PartIsNotSlot"),
+            RmrkError::PaymentTokenCallFailed => String::from("PaymentTokenCallFailed"),
+            RmrkError::PaymentTransferFailed => String::from("PaymentTransferFailed"),
+            RmrkError::PendingChildNotExpired => String::from("PendingChildNotExpired"),
+            RmrkError::PublicSaleNotStarted => String::from("PublicSaleNotStarted"),
+            RmrkError::ReservedSupplyLocked => String::from("ReservedSupplyLocked"),
+            RmrkError::RevealAlreadyCommitted => String::from("RevealAlreadyCommitted"),
+            RmrkError::RevealCommitmentMismatch => String::from("RevealCommitmentMismatch"),
+            RmrkError::RevealNotCommitted => String::from("RevealNotCommitted"),
+            RmrkError::RootOwnerNotFound => String::from("RootOwnerNotFound"),
+            RmrkError::RoyaltyTooHigh => String::from("RoyaltyTooHigh"),
+            RmrkError::SalePhaseSupplyExceeded => String::from("SalePhaseSupplyExceeded"),
             RmrkError::SlotAlreadyUsed => String::from("SlotAlreadyUsed"),
             RmrkError::TargetAssetCannotReceiveSlot => String::from("TargetAssetCannotReceiveSlot"),
             RmrkError::UnknownEquippableAsset => String::from("UnknownEquippableAsset"),
             RmrkError::UnknownPart => String::from("UnknownPart"),
             RmrkError::UnknownPartId => String::from("UnknownPartId"),
+            RmrkError::UnsupportedIdVariant => String::from("UnsupportedIdVariant"),
             RmrkError::UriNotFound => String::from("UriNotFound"),
             RmrkError::WithdrawalFailed => String::from("WithdrawalFailed"),
         }
@@ -4,6 +4,8 @@
 
 pub mod errors;
 pub mod roles;
+pub mod soulbound;
+pub mod splitter;
 pub mod types;
 pub mod utils;
 pub mod counter;
@@ -0,0 +1,139 @@
+//! Soulbound (non-transferable) tokens
+//!
+//! Tokens can be locked for the whole collection, or one by one before they are minted.
+//! Locked tokens can still be minted and burned. Collections supporting nesting can let
+//! locked tokens move in and out of parents in this collection or in approved child
+//! collections, as long as their root owner is kept.
+
+use ink::storage::Mapping;
+
+use crate::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+    utils::Utils,
+};
+
+use openbrush::{
+    contracts::{
+        access_control::*,
+        psp34::{
+            Id,
+            PSP34Error,
+        },
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+pub const STORAGE_SOULBOUND_KEY: u32 = openbrush::storage_unique_key!(SoulboundData);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_SOULBOUND_KEY)]
+pub struct SoulboundData {
+    pub collection_locked: bool,
+    pub locked_tokens: Mapping<Id, ()>,
+}
+
+#[openbrush::wrapper]
+pub type SoulboundRef = dyn Soulbound;
+
+/// Trait definitions for Soulbound functions
+#[openbrush::trait_definition]
+pub trait Soulbound {
+    /// Lock all tokens of the collection. Locking can not be undone.
+    #[ink(message)]
+    fn lock_collection(&mut self) -> Result<()>;
+
+    /// Lock the token before it is minted, so that it never changes hands after mint.
+    /// Locking can not be undone.
+    #[ink(message)]
+    fn lock_token(&mut self, token_id: Id) -> Result<()>;
+
+    /// Check if the token is locked.
+    #[ink(message)]
+    fn locked(&self, token_id: Id) -> Result<bool>;
+
+    /// Ensure that the token transfer is allowed.
+    fn ensure_transferable(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        token_id: &Id,
+    ) -> Result<()>;
+}
+
+/// Trait definitions for Soulbound events
+pub trait SoulboundEvents {
+    /// Emit Locked event. `None` stands for the whole collection.
+    fn _emit_locked_event(&self, token_id: Option<Id>);
+}
+
+impl<T> Soulbound for T
+where
+    T: Storage<SoulboundData> + Storage<access_control::Data> + SoulboundEvents + Utils,
+{
+    /// Lock all tokens of the collection
+    #[modifiers(only_role(ADMIN))]
+    default fn lock_collection(&mut self) -> Result<()> {
+        self.data::<SoulboundData>().collection_locked = true;
+        self._emit_locked_event(None);
+        Ok(())
+    }
+
+    /// Lock the token before it is minted
+    #[modifiers(only_role(ADMIN))]
+    default fn lock_token(&mut self, token_id: Id) -> Result<()> {
+        if self.ensure_exists_and_get_owner(&token_id).is_ok() {
+            return Err(PSP34Error::TokenExists.into())
+        }
+        self.data::<SoulboundData>()
+            .locked_tokens
+            .insert(&token_id, &());
+        self._emit_locked_event(Some(token_id));
+        Ok(())
+    }
+
+    /// Check if the token is locked
+    default fn locked(&self, token_id: Id) -> Result<bool> {
+        self.ensure_exists_and_get_owner(&token_id)?;
+        let soulbound = self.data::<SoulboundData>();
+        Ok(soulbound.collection_locked || soulbound.locked_tokens.contains(&token_id))
+    }
+
+    /// Allow minting and burning of a locked token, any other transfer is rejected
+    default fn ensure_transferable(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        token_id: &Id,
+    ) -> Result<()> {
+        let (from, to) = match (from, to) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+        if from == to {
+            return Ok(())
+        }
+        let soulbound = self.data::<SoulboundData>();
+        if !soulbound.collection_locked && !soulbound.locked_tokens.contains(token_id) {
+            return Ok(())
+        }
+
+        Err(RmrkError::CannotTransferSoulbound.into())
+    }
+}
+
+/// Event trait for Soulbound
+impl<T> SoulboundEvents for T
+where
+    T: Storage<SoulboundData>,
+{
+    /// Emit Locked event
+    default fn _emit_locked_event(&self, _token_id: Option<Id>) {}
+}
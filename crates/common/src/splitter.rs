@@ -0,0 +1,411 @@
+//! Payment splitter for the contract's revenue
+//!
+//! Revenue is recorded as it is received, in native currency or in PSP22 tokens,
+//! and payees pull their share of it with `release` and `release_psp22`.
+//! `Utils::withdraw` is not limited by the splitter, since it sends the whole balance to the admin.
+
+use ink::{
+    env::CallFlags,
+    prelude::vec::Vec,
+    storage::Mapping,
+};
+
+use crate::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+};
+
+use openbrush::{
+    contracts::{
+        access_control::*,
+        psp22::PSP22Ref,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_PAYMENT_SPLITTER_KEY: u32 = openbrush::storage_unique_key!(PaymentSplitterData);
+
+/// Revenue is tracked per currency, `None` being the native currency and `Some` a PSP22 token
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_PAYMENT_SPLITTER_KEY)]
+pub struct PaymentSplitterData {
+    pub payees: Vec<AccountId>,
+    pub shares: Mapping<AccountId, u128>,
+    pub total_shares: u128,
+    pub tokens: Vec<AccountId>,
+    pub received: Mapping<Option<AccountId>, Balance>,
+    pub released: Mapping<(Option<AccountId>, AccountId), Balance>,
+    pub total_released: Mapping<Option<AccountId>, Balance>,
+    pub owed: Mapping<(Option<AccountId>, AccountId), Balance>,
+}
+
+#[openbrush::wrapper]
+pub type PaymentSplitterRef = dyn PaymentSplitter;
+
+/// Trait definitions for PaymentSplitter functions
+#[openbrush::trait_definition]
+pub trait PaymentSplitter {
+    /// Replace the payees and their shares.
+    /// Payment due to the current payees stays owed to them and can still be released.
+    #[ink(message)]
+    fn set_payees(&mut self, payees: Vec<(AccountId, u128)>) -> Result<()>;
+
+    /// Release the payment due to the payee.
+    #[ink(message)]
+    fn release(&mut self, payee: AccountId) -> Result<()>;
+
+    /// Release the payment in the PSP22 token due to the payee.
+    #[ink(message)]
+    fn release_psp22(&mut self, token: AccountId, payee: AccountId) -> Result<()>;
+
+    /// Get the list of payees.
+    #[ink(message)]
+    fn payees(&self) -> Vec<AccountId>;
+
+    /// Get the shares of the account.
+    #[ink(message)]
+    fn shares(&self, account: AccountId) -> u128;
+
+    /// Get the sum of all shares.
+    #[ink(message)]
+    fn total_shares(&self) -> u128;
+
+    /// Get the revenue received since the payees were set.
+    #[ink(message)]
+    fn total_received(&self) -> Balance;
+
+    /// Get the amount already released to the account since the payees were set.
+    #[ink(message)]
+    fn released(&self, account: AccountId) -> Balance;
+
+    /// Get the amount already released to all payees since the payees were set.
+    #[ink(message)]
+    fn total_released(&self) -> Balance;
+
+    /// Get the amount that can be released to the account.
+    #[ink(message)]
+    fn pending_payment(&self, account: AccountId) -> Balance;
+
+    /// Get the revenue in the PSP22 token received since the payees were set.
+    #[ink(message)]
+    fn psp22_total_received(&self, token: AccountId) -> Balance;
+
+    /// Get the amount of the PSP22 token already released to the account since the payees were set.
+    #[ink(message)]
+    fn psp22_released(&self, token: AccountId, account: AccountId) -> Balance;
+
+    /// Get the amount of the PSP22 token that can be released to the account.
+    #[ink(message)]
+    fn psp22_pending_payment(&self, token: AccountId, account: AccountId) -> Balance;
+
+    /// Record revenue received in native currency, or in the PSP22 token.
+    fn record_revenue(&mut self, token: Option<AccountId>, amount: Balance) -> Result<()>;
+}
+
+/// Trait definitions for PaymentSplitter events
+pub trait PaymentSplitterEvents {
+    /// Emit PayeeAdded event.
+    fn _emit_payee_added_event(&self, account: AccountId, shares: u128);
+
+    /// Emit PaymentReleased event.
+    fn _emit_payment_released_event(&self, to: AccountId, amount: Balance);
+
+    /// Emit Psp22PaymentReleased event.
+    fn _emit_psp22_payment_released_event(&self, token: AccountId, to: AccountId, amount: Balance);
+}
+
+/// Hook for the revenue received by modules which don't depend on the splitter
+pub trait RevenueHooks {
+    /// Called with the payment received in native currency, or in the PSP22 token.
+    /// Nothing is recorded by default. Contracts with the splitter override it
+    /// with `PaymentSplitter::record_revenue`.
+    fn _on_revenue_received(&mut self, token: Option<AccountId>, amount: Balance) -> Result<()>;
+}
+
+impl<T> PaymentSplitter for T
+where
+    T: Storage<PaymentSplitterData> + Storage<access_control::Data> + PaymentSplitterEvents,
+{
+    /// Replace the payees and their shares
+    #[modifiers(only_role(ADMIN))]
+    default fn set_payees(&mut self, payees: Vec<(AccountId, u128)>) -> Result<()> {
+        let mut currencies = Vec::from([None]);
+        currencies.extend(
+            self.data::<PaymentSplitterData>()
+                .tokens
+                .iter()
+                .map(|token| Some(*token)),
+        );
+        for token in currencies {
+            for payee in self.data::<PaymentSplitterData>().payees.clone() {
+                let due = due_payment(&*self, token, payee);
+                let splitter = self.data::<PaymentSplitterData>();
+                if due > 0 {
+                    let owed = splitter
+                        .owed
+                        .get((token, payee))
+                        .unwrap_or_default()
+                        .checked_add(due)
+                        .ok_or(RmrkError::Overflow)?;
+                    splitter.owed.insert((token, payee), &owed);
+                }
+                splitter.released.remove((token, payee));
+            }
+            let splitter = self.data::<PaymentSplitterData>();
+            splitter.received.remove(token);
+            splitter.total_released.remove(token);
+        }
+
+        let splitter = self.data::<PaymentSplitterData>();
+        for payee in splitter.payees.iter() {
+            splitter.shares.remove(payee);
+        }
+        splitter.payees = Vec::new();
+        splitter.total_shares = 0;
+
+        for (account, shares) in payees {
+            let splitter = self.data::<PaymentSplitterData>();
+            if shares == 0 || splitter.shares.contains(account) {
+                return Err(RmrkError::BadConfig.into())
+            }
+            splitter.shares.insert(account, &shares);
+            splitter.payees.push(account);
+            splitter.total_shares = splitter
+                .total_shares
+                .checked_add(shares)
+                .ok_or(RmrkError::Overflow)?;
+            self._emit_payee_added_event(account, shares);
+        }
+
+        Ok(())
+    }
+
+    /// Release the payment due to the payee
+    default fn release(&mut self, payee: AccountId) -> Result<()> {
+        let payment = settle_payment(self, None, payee)?;
+        Self::env()
+            .transfer(payee, payment)
+            .map_err(|_| RmrkError::WithdrawalFailed)?;
+        self._emit_payment_released_event(payee, payment);
+        Ok(())
+    }
+
+    /// Release the payment in the PSP22 token due to the payee
+    default fn release_psp22(&mut self, token: AccountId, payee: AccountId) -> Result<()> {
+        let payment = settle_payment(self, Some(token), payee)?;
+        transfer_psp22(token, payee, payment)?;
+        self._emit_psp22_payment_released_event(token, payee, payment);
+        Ok(())
+    }
+
+    /// Get the list of payees
+    default fn payees(&self) -> Vec<AccountId> {
+        self.data::<PaymentSplitterData>().payees.clone()
+    }
+
+    /// Get the shares of the account
+    default fn shares(&self, account: AccountId) -> u128 {
+        self.data::<PaymentSplitterData>()
+            .shares
+            .get(account)
+            .unwrap_or_default()
+    }
+
+    /// Get the sum of all shares
+    default fn total_shares(&self) -> u128 {
+        self.data::<PaymentSplitterData>().total_shares
+    }
+
+    /// Get the revenue received since the payees were set
+    default fn total_received(&self) -> Balance {
+        self.data::<PaymentSplitterData>()
+            .received
+            .get(None)
+            .unwrap_or_default()
+    }
+
+    /// Get the amount already released to the account
+    default fn released(&self, account: AccountId) -> Balance {
+        self.data::<PaymentSplitterData>()
+            .released
+            .get((None, account))
+            .unwrap_or_default()
+    }
+
+    /// Get the amount already released to all payees
+    default fn total_released(&self) -> Balance {
+        self.data::<PaymentSplitterData>()
+            .total_released
+            .get(None)
+            .unwrap_or_default()
+    }
+
+    /// Get the amount that can be released to the account
+    default fn pending_payment(&self, account: AccountId) -> Balance {
+        pending_payment_of(self, None, account)
+    }
+
+    /// Get the revenue in the PSP22 token received since the payees were set
+    default fn psp22_total_received(&self, token: AccountId) -> Balance {
+        self.data::<PaymentSplitterData>()
+            .received
+            .get(Some(token))
+            .unwrap_or_default()
+    }
+
+    /// Get the amount of the PSP22 token already released to the account
+    default fn psp22_released(&self, token: AccountId, account: AccountId) -> Balance {
+        self.data::<PaymentSplitterData>()
+            .released
+            .get((Some(token), account))
+            .unwrap_or_default()
+    }
+
+    /// Get the amount of the PSP22 token that can be released to the account
+    default fn psp22_pending_payment(&self, token: AccountId, account: AccountId) -> Balance {
+        pending_payment_of(self, Some(token), account)
+    }
+
+    /// Record revenue received in native currency, or in the PSP22 token
+    default fn record_revenue(&mut self, token: Option<AccountId>, amount: Balance) -> Result<()> {
+        let splitter = self.data::<PaymentSplitterData>();
+        let received = splitter
+            .received
+            .get(token)
+            .unwrap_or_default()
+            .checked_add(amount)
+            .ok_or(RmrkError::Overflow)?;
+        splitter.received.insert(token, &received);
+        if let Some(token) = token {
+            if !splitter.tokens.contains(&token) {
+                splitter.tokens.push(token);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Get the share of the received revenue due to the account and not released yet
+fn due_payment<T>(instance: &T, token: Option<AccountId>, account: AccountId) -> Balance
+where
+    T: Storage<PaymentSplitterData>,
+{
+    let splitter = instance.data::<PaymentSplitterData>();
+    if splitter.total_shares == 0 {
+        return 0
+    }
+    let shares = splitter.shares.get(account).unwrap_or_default();
+    let received = splitter.received.get(token).unwrap_or_default();
+    let due = received
+        .checked_mul(shares)
+        .map(|amount| amount / splitter.total_shares)
+        .unwrap_or_else(|| received / splitter.total_shares * shares);
+
+    due.saturating_sub(splitter.released.get((token, account)).unwrap_or_default())
+}
+
+/// Get the amount that can be released to the account,
+/// including payment owed from before the payees were replaced
+fn pending_payment_of<T>(instance: &T, token: Option<AccountId>, account: AccountId) -> Balance
+where
+    T: Storage<PaymentSplitterData>,
+{
+    let owed = instance
+        .data::<PaymentSplitterData>()
+        .owed
+        .get((token, account))
+        .unwrap_or_default();
+    due_payment(instance, token, account).saturating_add(owed)
+}
+
+/// Record the payment due to the payee as released and return its amount
+fn settle_payment<T>(
+    instance: &mut T,
+    token: Option<AccountId>,
+    payee: AccountId,
+) -> Result<Balance>
+where
+    T: Storage<PaymentSplitterData>,
+{
+    let splitter = instance.data::<PaymentSplitterData>();
+    let owed = splitter.owed.get((token, payee)).unwrap_or_default();
+    if owed == 0 && !splitter.shares.contains(payee) {
+        return Err(RmrkError::AccountHasNoShares.into())
+    }
+    let due = due_payment(&*instance, token, payee);
+    let payment = due.checked_add(owed).ok_or(RmrkError::Overflow)?;
+    if payment == 0 {
+        return Err(RmrkError::NoPaymentDue.into())
+    }
+
+    let splitter = instance.data::<PaymentSplitterData>();
+    let released = splitter
+        .released
+        .get((token, payee))
+        .unwrap_or_default()
+        .checked_add(due)
+        .ok_or(RmrkError::Overflow)?;
+    splitter.released.insert((token, payee), &released);
+    let total_released = splitter
+        .total_released
+        .get(token)
+        .unwrap_or_default()
+        .checked_add(due)
+        .ok_or(RmrkError::Overflow)?;
+    splitter.total_released.insert(token, &total_released);
+    splitter.owed.remove((token, payee));
+    Ok(payment)
+}
+
+/// Transfer `amount` of the PSP22 `token` from the contract to `to`
+fn transfer_psp22(token: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+    match PSP22Ref::transfer_builder(&token, to, amount, Vec::new())
+        .call_flags(CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+    {
+        Ok(Ok(Ok(()))) => Ok(()),
+        Ok(Ok(Err(_))) => Err(RmrkError::PaymentTransferFailed.into()),
+        _ => Err(RmrkError::PaymentTokenCallFailed.into()),
+    }
+}
+
+/// Event trait for PaymentSplitter
+impl<T> PaymentSplitterEvents for T
+where
+    T: Storage<PaymentSplitterData>,
+{
+    /// Emit PayeeAdded event
+    default fn _emit_payee_added_event(&self, _account: AccountId, _shares: u128) {}
+
+    /// Emit PaymentReleased event
+    default fn _emit_payment_released_event(&self, _to: AccountId, _amount: Balance) {}
+
+    /// Emit Psp22PaymentReleased event
+    default fn _emit_psp22_payment_released_event(
+        &self,
+        _token: AccountId,
+        _to: AccountId,
+        _amount: Balance,
+    ) {
+    }
+}
+
+/// Revenue hook which records nothing by default
+impl<T> RevenueHooks for T {
+    /// Record nothing
+    default fn _on_revenue_received(
+        &mut self,
+        _token: Option<AccountId>,
+        _amount: Balance,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
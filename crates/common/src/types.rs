@@ -5,7 +5,9 @@ use openbrush::{
     contracts::psp34::Id,
     traits::{
         AccountId,
+        Balance,
         String,
+        Timestamp,
     },
 };
 
@@ -88,3 +90,29 @@ pub struct Equipment {
     // child_id: The (Address of the collection, token ID) of token that is equipped
     pub child_nft: ChildNft,
 }
+
+/// Used to define a time-boxed sale phase
+#[derive(scale::Encode, scale::Decode, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct SalePhase {
+    // start: Timestamp from which the phase is active (inclusive)
+    pub start: Timestamp,
+
+    // end: Timestamp at which the phase ends (exclusive)
+    pub end: Timestamp,
+
+    // price: Price per token. Starting price for Dutch auction phase
+    pub price: Balance,
+
+    // floor_price: If set, price drops linearly from `price` to `floor_price` during the phase
+    pub floor_price: Option<Balance>,
+
+    // supply: Maximum number of tokens that can be minted during the phase
+    pub supply: Option<u64>,
+
+    // minted: Number of tokens minted during the phase
+    pub minted: u64,
+}
@@ -1,6 +1,13 @@
 //! Set of functions commonly used with PSP34 contract
 
-use ink::prelude::string::String as PreludeString;
+use ink::{
+    env::CallFlags,
+    prelude::{
+        string::String as PreludeString,
+        vec,
+        vec::Vec,
+    },
+};
 
 use crate::{
     errors::{
@@ -16,6 +23,7 @@ use crate::{
 use openbrush::{
     contracts::{
         access_control::*,
+        psp22::PSP22Ref,
         psp34::extensions::{
             enumerable::*,
             metadata::*,
@@ -34,6 +42,7 @@ use openbrush::{
 #[openbrush::trait_definition]
 pub trait Utils {
     /// Set new value for the baseUri.
+    /// Fails once the baseUri is frozen.
     #[ink(message)]
     fn set_base_uri(&mut self, uri: PreludeString) -> Result<()>;
 
@@ -41,6 +50,13 @@ pub trait Utils {
     #[ink(message)]
     fn withdraw(&mut self) -> Result<()>;
 
+    /// Withdraw contract's balance of the PSP22 token.
+    #[ink(message)]
+    fn withdraw_psp22(&mut self, token: AccountId) -> Result<()>;
+
+    /// Freeze the baseUri, so that it can no longer be changed
+    fn freeze_base_uri(&mut self);
+
     /// Ensure that token exists
     fn ensure_exists_and_get_owner(&self, id: &Id) -> Result<AccountId>;
 
@@ -63,6 +79,12 @@ where
         let id = self
             .data::<psp34::Data<enumerable::Balances>>()
             .collection_id();
+        if self
+            .get_attribute(id.clone(), String::from("baseUriFrozen"))
+            .is_some()
+        {
+            return Err(RmrkError::BaseUriFrozen.into())
+        }
         self.data::<metadata::Data>()
             ._set_attribute(id, String::from("baseUri"), uri.into_bytes());
         Ok(())
@@ -81,6 +103,35 @@ where
             .map_err(|_| RmrkError::WithdrawalFailed)?;
         Ok(())
     }
+
+    /// Withdraw contract's balance of the PSP22 token
+    #[modifiers(only_role(ADMIN))]
+    default fn withdraw_psp22(&mut self, token: AccountId) -> Result<()> {
+        let caller = Self::env().caller();
+        let balance =
+            match PSP22Ref::balance_of_builder(&token, Self::env().account_id()).try_invoke() {
+                Ok(Ok(balance)) => balance,
+                _ => return Err(RmrkError::PaymentTokenCallFailed.into()),
+            };
+        match PSP22Ref::transfer_builder(&token, caller, balance, Vec::new())
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+        {
+            Ok(Ok(Ok(()))) => Ok(()),
+            Ok(Ok(Err(_))) => Err(RmrkError::PaymentTransferFailed.into()),
+            _ => Err(RmrkError::PaymentTokenCallFailed.into()),
+        }
+    }
+
+    /// Freeze the baseUri, so that it can no longer be changed
+    default fn freeze_base_uri(&mut self) {
+        let id = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .collection_id();
+        self.data::<metadata::Data>()
+            ._set_attribute(id, String::from("baseUriFrozen"), vec![1]);
+    }
+
     /// Check if token is minted. Return the owner
     default fn ensure_exists_and_get_owner(&self, id: &Id) -> Result<AccountId> {
         let token_owner = self
@@ -99,3 +150,24 @@ where
         Ok(())
     }
 }
+
+/// Hook for the ownership checks of modules which don't depend on nesting
+pub trait OwnershipHooks {
+    /// Ensure that the account can manage the token, e.g. accept its assets or equip it.
+    /// Only the token owner can by default. Contracts with nesting override it
+    /// with `Ownership::ensure_root_owner`, to let the root owner manage nested tokens.
+    fn _ensure_root_owner(&self, account: AccountId, token_id: &Id) -> Result<()>;
+}
+
+impl<T> OwnershipHooks for T
+where
+    T: Utils,
+{
+    /// Ensure that the account owns the token
+    default fn _ensure_root_owner(&self, account: AccountId, token_id: &Id) -> Result<()> {
+        if self.ensure_exists_and_get_owner(token_id)? != account {
+            return Err(RmrkError::NotTokenOwner.into())
+        }
+        Ok(())
+    }
+}
@@ -15,7 +15,10 @@ use rmrk_common::{
         RmrkError,
     },
     types::*,
-    utils::Utils,
+    utils::{
+        OwnershipHooks,
+        Utils,
+    },
 };
 
 use rmrk_multiasset::{
@@ -61,6 +64,7 @@ where
         + MultiAsset
         + MultiAssetInternal
         + Internal
+        + OwnershipHooks
         + Utils,
 {
     /// Used to equip a child nft into a token.
@@ -72,18 +76,24 @@ where
         child_nft: ChildNft,
         child_asset_id: AssetId,
     ) -> Result<()> {
-        let token_owner = self.ensure_exists_and_get_owner(&token_id)?;
-        self.ensure_token_owner(token_owner)?;
+        self._ensure_root_owner(Self::env().caller(), &token_id)?;
         self.ensure_asset_accepts_slot(&asset_id, &slot_part_id)?;
         self.ensure_token_slot_free(&token_id, &slot_part_id)?;
 
-        EquippableRef::ensure_token_can_be_equipped_with_asset_into_slot(
+        // Errors returned by the child collection are passed through unchanged
+        match EquippableRef::ensure_token_can_be_equipped_with_asset_into_slot_builder(
             &child_nft.0,
             Self::env().account_id(),
             child_nft.1.clone(),
             child_asset_id,
             slot_part_id,
-        )?;
+        )
+        .try_invoke()
+        {
+            Ok(Ok(result)) => result?,
+            Ok(Err(err)) => return Err(RmrkError::EquippableCallFailed(err.into()).into()),
+            Err(err) => return Err(RmrkError::EquippableCallFailed(err.into()).into()),
+        }
 
         // Check from base perspective. If catalog for this asset is None, then it is not equippable.
         match self
@@ -93,7 +103,17 @@ where
             .ok_or(RmrkError::CatalogNotFoundForAsset)?
         {
             Some(catalog_address) => {
-                CatalogRef::ensure_equippable(&catalog_address, slot_part_id, child_nft.0)?;
+                match CatalogRef::ensure_equippable_builder(
+                    &catalog_address,
+                    slot_part_id,
+                    child_nft.0,
+                )
+                .try_invoke()
+                {
+                    Ok(Ok(result)) => result?,
+                    Ok(Err(err)) => return Err(RmrkError::CatalogCallFailed(err.into()).into()),
+                    Err(err) => return Err(RmrkError::CatalogCallFailed(err.into()).into()),
+                }
             }
             None => return Err(RmrkError::AssetIdNotEquippable.into()),
         }
@@ -114,8 +134,7 @@ where
 
     /// Used to unequip child from parent token.
     default fn unequip(&mut self, token_id: Id, slot_part_id: PartId) -> Result<()> {
-        let token_owner = self.ensure_exists_and_get_owner(&token_id)?;
-        self.ensure_token_owner(token_owner)?;
+        self._ensure_root_owner(Self::env().caller(), &token_id)?;
         let equipment = self.ensure_equipped(&token_id, &slot_part_id)?;
 
         self.data::<EquippableData>()
@@ -0,0 +1,119 @@
+use crate::{
+    extensions::autoindex::{
+        MintingAutoIndexData,
+        MintingAutoIndexInternal,
+    },
+    internal::Internal,
+    traits::MintingAllowlist,
+    MintingData,
+};
+
+use ink::storage::Mapping;
+
+use openbrush::{
+    contracts::{
+        access_control::{
+            self,
+            only_role,
+        },
+        psp34::extensions::enumerable::*,
+        reentrancy_guard::*,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
+};
+
+pub const STORAGE_MINTING_ALLOWLIST: u32 = openbrush::storage_unique_key!(MintingAllowlist);
+
+/// Storage for presale allowlist.
+/// Each allowlisted account holds its remaining quota and presale price per token.
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_MINTING_ALLOWLIST)]
+pub struct MintingAllowlistData {
+    pub allowlist: Mapping<AccountId, (u64, Balance)>,
+}
+
+impl<T> MintingAllowlist for T
+where
+    T: Storage<MintingData>
+        + Storage<MintingAllowlistData>
+        + Storage<MintingAutoIndexData>
+        + Storage<access_control::Data>
+        + Storage<reentrancy_guard::Data>
+        + Storage<psp34::Data<enumerable::Balances>>
+        + MintingAutoIndexInternal
+        + psp34::Internal
+        + Internal,
+{
+    /// Add account to the allowlist, or update its quota and presale price.
+    #[modifiers(only_role(ADMIN))]
+    default fn add_to_allowlist(
+        &mut self,
+        account: AccountId,
+        quota: u64,
+        price: Balance,
+    ) -> Result<()> {
+        self.data::<MintingAllowlistData>()
+            .allowlist
+            .insert(account, &(quota, price));
+        Ok(())
+    }
+
+    /// Remove account from the allowlist.
+    #[modifiers(only_role(ADMIN))]
+    default fn remove_from_allowlist(&mut self, account: AccountId) -> Result<()> {
+        self.data::<MintingAllowlistData>()
+            .allowlist
+            .remove(account);
+        Ok(())
+    }
+
+    /// Set the timestamp from which public minting is open.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_public_sale_start(&mut self, start: Option<Timestamp>) -> Result<()> {
+        self.data::<MintingData>().public_sale_start = start;
+        Ok(())
+    }
+
+    /// Purchase tokens at the presale price, within the caller's allowlist quota.
+    #[modifiers(non_reentrant)]
+    default fn presale_mint(&mut self, mint_amount: u64) -> Result<()> {
+        let caller = Self::env().caller();
+        let (quota, price) = self
+            .data::<MintingAllowlistData>()
+            .allowlist
+            .get(caller)
+            .ok_or(RmrkError::NotAllowlisted)?;
+        if mint_amount > quota {
+            return Err(RmrkError::AllowlistQuotaExceeded.into())
+        }
+        self._collect_payment(price, mint_amount, false)?;
+        MintingAutoIndexInternal::mint_many(self, caller, mint_amount)?;
+        self.data::<MintingAllowlistData>()
+            .allowlist
+            .insert(caller, &(quota - mint_amount, price));
+        Ok(())
+    }
+
+    /// Get remaining quota and presale price for the account, if allowlisted.
+    default fn allowlist_entry(&self, account: AccountId) -> Option<(u64, Balance)> {
+        self.data::<MintingAllowlistData>().allowlist.get(account)
+    }
+
+    /// Get the timestamp from which public minting is open.
+    default fn public_sale_start(&self) -> Option<Timestamp> {
+        self.data::<MintingData>().public_sale_start
+    }
+}
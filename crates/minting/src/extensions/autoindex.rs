@@ -20,7 +20,10 @@ use openbrush::{
     },
 };
 use rmrk_common::{
-    counter::Counter,
+    counter::{
+        Counter,
+        IdGenerator,
+    },
     errors::{
         Result,
         RmrkError,
@@ -65,6 +68,13 @@ pub trait MintingAutoIndexInternal {
 
     /// Mint one or more tokens to the specified account, with auto-generated Ids
     fn mint_many(&mut self, to: AccountId, mint_amount: u64) -> Result<(Id, Id)>;
+
+    /// Mint a range of tokens with auto-generated Ids, without checking or tracking mint limits
+    fn _mint_range(&mut self, to: AccountId, mint_amount: u64) -> Result<(Id, Id)>;
+
+    /// Generate the Id of the next token to mint.
+    /// Override to plug in a custom `IdGenerator`, e.g. for `Id::Bytes` ids.
+    fn _next_token_id(&mut self) -> Result<Id>;
 }
 
 impl<T> MintingAutoIndexInternal for T
@@ -75,20 +85,32 @@ where
         + Internal,
 {
     /// Mint one token to the specified account, with auto-generated Id
-    fn mint(&mut self, to: AccountId) -> Result<Id> {
-        let next_id = self.data::<MintingAutoIndexData>().token_id.next()?;
-        self._check_amount(1)?;
-        self._mint_to(to, next_id.clone())?;
+    default fn mint(&mut self, to: AccountId) -> Result<Id> {
+        self._check_mint_limits(to, 1)?;
+        let next_id = mint_next(self, to)?;
+        self._track_minted(to, 1);
         Ok(next_id)
     }
 
     /// Mint one or more tokens to the specified account, with auto-generated Ids
     /// The returned range provides the first and last generated Id
-    fn mint_many(&mut self, to: AccountId, mint_amount: u64) -> Result<(Id, Id)> {
+    default fn mint_many(&mut self, to: AccountId, mint_amount: u64) -> Result<(Id, Id)> {
+        self._check_mint_limits(to, mint_amount)?;
+        let range = self._mint_range(to, mint_amount)?;
+        self._track_minted(to, mint_amount);
+        Ok(range)
+    }
+
+    /// Mint a range of tokens with auto-generated Ids, without checking or tracking mint limits
+    /// The returned range provides the first and last generated Id
+    default fn _mint_range(&mut self, to: AccountId, mint_amount: u64) -> Result<(Id, Id)> {
+        if mint_amount == 0 {
+            return Err(RmrkError::CannotMintZeroTokens.into())
+        }
         let mut token_ids = vec![];
 
         for i in 0..mint_amount {
-            let next_id = <Self as MintingAutoIndexInternal>::mint(self, to)?;
+            let next_id = mint_next(self, to)?;
 
             if i == 0 || i == (mint_amount - 1) {
                 token_ids.push(next_id.clone());
@@ -100,4 +122,20 @@ where
 
         Ok((range_start, range_end))
     }
+
+    /// Generate the Id of the next token to mint from the `Id` counter
+    default fn _next_token_id(&mut self) -> Result<Id> {
+        self.data::<MintingAutoIndexData>().token_id.next_id()
+    }
+}
+
+/// Mint the next token to the specified account, mint limits are checked by the caller
+fn mint_next<T>(instance: &mut T, to: AccountId) -> Result<Id>
+where
+    T: MintingAutoIndexInternal + psp34::Internal + Internal,
+{
+    let next_id = instance._next_token_id()?;
+    instance._check_amount(1)?;
+    instance._mint_to(to, next_id.clone())?;
+    Ok(next_id)
 }
@@ -1,30 +1,96 @@
 use crate::MintingData;
 
-use rmrk_common::errors::{
-    Result,
-    RmrkError,
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    splitter::RevenueHooks,
+    types::SalePhase,
 };
 
-use ink::prelude::string::String as PreludeString;
+use ink::{
+    env::CallFlags,
+    prelude::{
+        format,
+        string::{
+            String as PreludeString,
+            ToString,
+        },
+        vec::Vec,
+    },
+};
 
 use openbrush::{
-    contracts::psp34::{
-        balances::BalancesManager,
-        extensions::enumerable::*,
+    contracts::{
+        psp22::PSP22Ref,
+        psp34::{
+            balances::BalancesManager,
+            extensions::enumerable::*,
+        },
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
     },
-    traits::Storage,
 };
 
 /// Trait definitions for Minting internal functions.
 pub trait Internal {
-    /// Check if the transferred mint values is as expected.
-    fn _check_value(&self, transfered_value: u128, mint_amount: u64) -> Result<()>;
+    /// Check if the transferred mint values is as expected for the given price.
+    fn _check_value_for_price(
+        &self,
+        transfered_value: u128,
+        price: Balance,
+        mint_amount: u64,
+    ) -> Result<()>;
+
+    /// Collect payment for minted tokens in native currency or in the PSP22 payment token.
+    /// With `refund_excess`, native value above the price is accepted and refunded.
+    /// The collected payment is reported to `RevenueHooks`.
+    fn _collect_payment(
+        &mut self,
+        price: Balance,
+        mint_amount: u64,
+        refund_excess: bool,
+    ) -> Result<()>;
+
+    /// Check if public minting is open.
+    fn _check_public_sale(&self) -> Result<()>;
 
     /// Check amount of tokens to be minted.
     fn _check_amount(&self, mint_amount: u64) -> Result<()>;
 
+    /// Get the active sale phase and its index.
+    fn _active_phase(&self) -> Option<(usize, SalePhase)>;
+
+    /// Get the price of the sale phase at the current block timestamp.
+    fn _phase_price(&self, phase: &SalePhase) -> Balance;
+
+    /// Get the current mint price, taking sale phases into account.
+    fn _current_price(&self) -> Result<Balance>;
+
+    /// Check if the active sale phase can supply the mint amount.
+    fn _check_phase_supply(&self, mint_amount: u64) -> Result<()>;
+
+    /// Record the number of tokens minted in the active sale phase.
+    fn _track_phase_minted(&mut self, mint_amount: u64);
+
+    /// Get amount of reserved supply unlocked by the vesting schedule.
+    fn _unlocked_reserved(&self) -> u64;
+
+    /// Check per-call and per-wallet mint limits for the account.
+    fn _check_mint_limits(&self, to: AccountId, mint_amount: u64) -> Result<()>;
+
+    /// Record the number of tokens minted by the account.
+    fn _track_minted(&mut self, to: AccountId, mint_amount: u64);
+
     /// Get URI for the token Id.
     fn _token_uri(&self, token_id: Id) -> Result<PreludeString>;
+
+    /// Get URI for the token Id derived from the collection baseUri.
+    fn _derived_token_uri(&self, token_id: Id) -> Result<PreludeString>;
 }
 
 /// Helper trait for Minting
@@ -33,13 +99,17 @@ where
     T: Storage<MintingData>
         + psp34::extensions::metadata::PSP34Metadata
         + psp34::Internal
-        + Storage<psp34::Data<enumerable::Balances>>,
+        + Storage<psp34::Data<enumerable::Balances>>
+        + RevenueHooks,
 {
-    /// Check if the transferred mint values is as expected
-    default fn _check_value(&self, transfered_value: u128, mint_amount: u64) -> Result<()> {
-        if let Some(value) =
-            (mint_amount as u128).checked_mul(self.data::<MintingData>().price_per_mint)
-        {
+    /// Check if the transferred mint values is as expected for the given price
+    default fn _check_value_for_price(
+        &self,
+        transfered_value: u128,
+        price: Balance,
+        mint_amount: u64,
+    ) -> Result<()> {
+        if let Some(value) = (mint_amount as u128).checked_mul(price) {
             if transfered_value == value {
                 return Ok(())
             }
@@ -48,17 +118,90 @@ where
         Err(RmrkError::BadMintValue.into())
     }
 
+    /// Collect payment for minted tokens in native currency or in the PSP22 payment token.
+    /// With `refund_excess`, native value above the price is refunded to the caller,
+    /// since a Dutch auction price depends on the block in which the mint is included.
+    default fn _collect_payment(
+        &mut self,
+        price: Balance,
+        mint_amount: u64,
+        refund_excess: bool,
+    ) -> Result<()> {
+        let transfered_value = Self::env().transferred_value();
+        let payment_token = match self.data::<MintingData>().payment_token {
+            Some(payment_token) => payment_token,
+            None if !refund_excess => {
+                self._check_value_for_price(transfered_value, price, mint_amount)?;
+                return self._on_revenue_received(None, transfered_value)
+            }
+            None => {
+                let value = (mint_amount as u128)
+                    .checked_mul(price)
+                    .ok_or(RmrkError::BadMintValue)?;
+                if transfered_value < value {
+                    return Err(RmrkError::BadMintValue.into())
+                }
+                let excess = transfered_value - value;
+                if excess > 0 {
+                    Self::env()
+                        .transfer(Self::env().caller(), excess)
+                        .map_err(|_| RmrkError::PaymentTransferFailed)?;
+                }
+                return self._on_revenue_received(None, value)
+            }
+        };
+
+        if transfered_value != 0 {
+            return Err(RmrkError::BadMintValue.into())
+        }
+        let value = (mint_amount as u128)
+            .checked_mul(price)
+            .ok_or(RmrkError::BadMintValue)?;
+
+        match PSP22Ref::transfer_from_builder(
+            &payment_token,
+            Self::env().caller(),
+            Self::env().account_id(),
+            value,
+            Vec::new(),
+        )
+        .call_flags(CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        {
+            Ok(Ok(Ok(()))) => self._on_revenue_received(Some(payment_token), value),
+            Ok(Ok(Err(_))) => Err(RmrkError::PaymentTransferFailed.into()),
+            _ => Err(RmrkError::PaymentTokenCallFailed.into()),
+        }
+    }
+
+    /// Check if public minting is open
+    default fn _check_public_sale(&self) -> Result<()> {
+        match self.data::<MintingData>().public_sale_start {
+            Some(start) if Self::env().block_timestamp() < start => {
+                Err(RmrkError::PublicSaleNotStarted.into())
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Check amount of tokens to be minted
     default fn _check_amount(&self, mint_amount: u64) -> Result<()> {
         if mint_amount == 0 {
             return Err(RmrkError::CannotMintZeroTokens.into())
         }
 
+        // Reserved supply which is not minted yet is not available for other mints
+        let minting = self.data::<MintingData>();
+        let unminted_reserved = minting
+            .reserved_supply
+            .saturating_sub(minting.reserved_minted);
+
         if let Some(amount) = self
             .data::<psp34::Data<enumerable::Balances>>()
             .balances
             .total_supply()
             .checked_add(mint_amount as u128)
+            .and_then(|amount| amount.checked_add(unminted_reserved as u128))
         {
             return match self.data::<MintingData>().max_supply {
                 Some(max_supply) if amount <= max_supply as u128 => Ok(()),
@@ -70,10 +213,162 @@ where
         Err(RmrkError::CollectionIsFull.into())
     }
 
+    /// Get the active sale phase and its index
+    default fn _active_phase(&self) -> Option<(usize, SalePhase)> {
+        let now = Self::env().block_timestamp();
+        self.data::<MintingData>()
+            .sale_phases
+            .iter()
+            .position(|phase| phase.start <= now && now < phase.end)
+            .map(|index| (index, self.data::<MintingData>().sale_phases[index].clone()))
+    }
+
+    /// Get the price of the sale phase at the current block timestamp
+    default fn _phase_price(&self, phase: &SalePhase) -> Balance {
+        match phase.floor_price {
+            Some(floor_price) if floor_price < phase.price && phase.start < phase.end => {
+                let elapsed = Self::env()
+                    .block_timestamp()
+                    .saturating_sub(phase.start)
+                    .min(phase.end - phase.start);
+                let drop = (phase.price - floor_price)
+                    .saturating_mul(elapsed as u128)
+                    .checked_div((phase.end - phase.start) as u128)
+                    .unwrap_or_default();
+                phase.price - drop
+            }
+            _ => phase.price,
+        }
+    }
+
+    /// Get the current mint price, taking sale phases into account
+    default fn _current_price(&self) -> Result<Balance> {
+        if self.data::<MintingData>().sale_phases.is_empty() {
+            return Ok(self.data::<MintingData>().price_per_mint)
+        }
+
+        let (_, phase) = self._active_phase().ok_or(RmrkError::NoActiveSalePhase)?;
+        Ok(self._phase_price(&phase))
+    }
+
+    /// Check if the active sale phase can supply the mint amount
+    default fn _check_phase_supply(&self, mint_amount: u64) -> Result<()> {
+        if let Some((_, phase)) = self._active_phase() {
+            if let Some(supply) = phase.supply {
+                match phase.minted.checked_add(mint_amount) {
+                    Some(total) if total <= supply => {}
+                    _ => return Err(RmrkError::SalePhaseSupplyExceeded.into()),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record the number of tokens minted in the active sale phase
+    default fn _track_phase_minted(&mut self, mint_amount: u64) {
+        if let Some((index, _)) = self._active_phase() {
+            let phase = &mut self.data::<MintingData>().sale_phases[index];
+            phase.minted = phase.minted.saturating_add(mint_amount);
+        }
+    }
+
+    /// Get amount of reserved supply unlocked by the vesting schedule
+    default fn _unlocked_reserved(&self) -> u64 {
+        let now = Self::env().block_timestamp();
+        self.data::<MintingData>()
+            .vesting_schedule
+            .iter()
+            .filter(|(unlock_time, _)| *unlock_time <= now)
+            .fold(0u64, |unlocked, (_, amount)| {
+                unlocked.saturating_add(*amount)
+            })
+    }
+
+    /// Check per-call and per-wallet mint limits for the account
+    default fn _check_mint_limits(&self, to: AccountId, mint_amount: u64) -> Result<()> {
+        let minting = self.data::<MintingData>();
+        if let Some(max_per_call) = minting.max_mint_per_call {
+            if mint_amount > max_per_call {
+                return Err(RmrkError::MintPerCallLimitExceeded.into())
+            }
+        }
+        if let Some(max_per_wallet) = minting.max_mint_per_wallet {
+            let minted = minting.minted_per_account.get(to).unwrap_or_default();
+            match minted.checked_add(mint_amount) {
+                Some(total) if total <= max_per_wallet => {}
+                _ => return Err(RmrkError::MintPerWalletLimitExceeded.into()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record the number of tokens minted by the account
+    default fn _track_minted(&mut self, to: AccountId, mint_amount: u64) {
+        let minting = self.data::<MintingData>();
+        let minted = minting.minted_per_account.get(to).unwrap_or_default();
+        minting
+            .minted_per_account
+            .insert(to, &minted.saturating_add(mint_amount));
+    }
+
     /// Get URI for the token Id.
+    /// Explicit token URI wins over placeholder and URI derived from baseUri
     default fn _token_uri(&self, token_id: Id) -> Result<PreludeString> {
-        self.get_attribute(token_id, PreludeString::from("token_uri").into())
+        if let Some(token_uri) = self
+            .get_attribute(token_id.clone(), PreludeString::from("token_uri").into())
             .and_then(|token_uri| PreludeString::from_utf8(token_uri).ok())
-            .ok_or(RmrkError::UriNotFound.into())
+        {
+            return Ok(token_uri)
+        }
+
+        let minting = self.data::<MintingData>();
+        if !minting.revealed {
+            if let Some(placeholder_uri) = minting
+                .placeholder_uri
+                .clone()
+                .and_then(|placeholder_uri| PreludeString::from_utf8(placeholder_uri).ok())
+            {
+                return Ok(placeholder_uri)
+            }
+        }
+
+        self._derived_token_uri(token_id)
+    }
+
+    /// Get URI for the token Id derived from the collection baseUri
+    default fn _derived_token_uri(&self, token_id: Id) -> Result<PreludeString> {
+        let collection_id = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .collection_id();
+        let base_uri = self
+            .get_attribute(collection_id, PreludeString::from("baseUri").into())
+            .and_then(|base_uri| PreludeString::from_utf8(base_uri).ok())
+            .ok_or(RmrkError::UriNotFound)?;
+        let id = match token_id {
+            Id::U8(id) => id.to_string(),
+            Id::U16(id) => id.to_string(),
+            Id::U32(id) => id.to_string(),
+            Id::U64(id) => id.to_string(),
+            Id::U128(id) => id.to_string(),
+            Id::Bytes(bytes) => {
+                PreludeString::from_utf8(bytes).unwrap_or_else(|err| {
+                    err.into_bytes()
+                        .iter()
+                        .map(|byte| format!("{:02x}", byte))
+                        .collect()
+                })
+            }
+        };
+
+        let suffix = self
+            .data::<MintingData>()
+            .token_uri_suffix
+            .clone()
+            .and_then(|suffix| PreludeString::from_utf8(suffix).ok())
+            .unwrap_or_default();
+
+        Ok(format!("{}{}{}", base_uri, id, suffix))
     }
 }
@@ -6,6 +6,7 @@
 pub mod internal;
 pub mod traits;
 pub mod extensions {
+    pub mod allowlist;
     pub mod autoindex;
 }
 
@@ -13,14 +14,24 @@ use extensions::autoindex::*;
 use internal::Internal;
 
 use rmrk_common::{
-    errors::Result,
-    roles::CONTRIBUTOR,
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::{
+        ADMIN,
+        CONTRIBUTOR,
+    },
+    types::SalePhase,
     utils::Utils,
 };
 
-use ink::prelude::{
-    string::String as PreludeString,
-    vec::Vec,
+use ink::{
+    prelude::{
+        string::String as PreludeString,
+        vec::Vec,
+    },
+    storage::Mapping,
 };
 
 use openbrush::{
@@ -41,12 +52,17 @@ use openbrush::{
         Balance,
         Storage,
         String,
+        Timestamp,
     },
 };
 
 use traits::{
     Minting,
     MintingLazy,
+    MintingLimits,
+    MintingPhases,
+    MintingReserved,
+    MintingReveal,
 };
 
 pub const STORAGE_MINTING_KEY: u32 = openbrush::storage_unique_key!(MintingData);
@@ -56,6 +72,19 @@ pub const STORAGE_MINTING_KEY: u32 = openbrush::storage_unique_key!(MintingData)
 pub struct MintingData {
     pub max_supply: Option<u64>,
     pub price_per_mint: Balance,
+    pub public_sale_start: Option<Timestamp>,
+    pub max_mint_per_wallet: Option<u64>,
+    pub max_mint_per_call: Option<u64>,
+    pub minted_per_account: Mapping<AccountId, u64>,
+    pub sale_phases: Vec<SalePhase>,
+    pub payment_token: Option<AccountId>,
+    pub placeholder_uri: Option<String>,
+    pub base_uri_commitment: Option<[u8; 32]>,
+    pub revealed: bool,
+    pub token_uri_suffix: Option<String>,
+    pub reserved_supply: u64,
+    pub reserved_minted: u64,
+    pub vesting_schedule: Vec<(Timestamp, u64)>,
 }
 
 impl<T> Minting for T
@@ -73,17 +102,22 @@ where
     #[modifiers(only_role(CONTRIBUTOR))]
     default fn mint(&mut self, to: AccountId, token_id: Id) -> Result<()> {
         self._check_amount(1)?;
+        self._check_mint_limits(to, 1)?;
         self._mint_to(to, token_id)?;
+        self._track_minted(to, 1);
         Ok(())
     }
 
     /// Mint many tokens to the specified account.
     #[modifiers(only_role(CONTRIBUTOR), non_reentrant)]
     default fn mint_many(&mut self, to: AccountId, token_ids: Vec<Id>) -> Result<()> {
-        self._check_amount(token_ids.len() as u64)?;
+        let mint_amount = token_ids.len() as u64;
+        self._check_amount(mint_amount)?;
+        self._check_mint_limits(to, mint_amount)?;
         for token_id in token_ids {
             self._mint_to(to, token_id)?;
         }
+        self._track_minted(to, mint_amount);
         Ok(())
     }
 
@@ -94,6 +128,13 @@ where
         Ok(())
     }
 
+    /// Set suffix appended to token URIs derived from the baseUri.
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_token_uri_suffix(&mut self, suffix: Option<String>) -> Result<()> {
+        self.data::<MintingData>().token_uri_suffix = suffix;
+        Ok(())
+    }
+
     /// Get max supply of tokens.
     default fn max_supply(&self) -> Option<u64> {
         self.data::<MintingData>().max_supply
@@ -109,6 +150,7 @@ where
 impl<T> MintingLazy for T
 where
     T: Storage<MintingData>
+        + Storage<access_control::Data>
         + MintingAutoIndexInternal
         + Storage<psp34::Data<enumerable::Balances>>
         + Storage<reentrancy_guard::Data>
@@ -118,17 +160,26 @@ where
         + Utils,
 {
     /// Purchase one token.
+    #[modifiers(non_reentrant)]
     default fn mint(&mut self) -> Result<()> {
-        self._check_value(Self::env().transferred_value(), 1)?;
+        self._check_public_sale()?;
+        let price = self._current_price()?;
+        self._collect_payment(price, 1, true)?;
+        self._check_phase_supply(1)?;
         MintingAutoIndexInternal::mint(self, Self::env().caller())?;
+        self._track_phase_minted(1);
         Ok(())
     }
 
     /// Purchase many tokens.
     #[modifiers(non_reentrant)]
     default fn mint_many(&mut self, mint_amount: u64) -> Result<()> {
-        self._check_value(Self::env().transferred_value(), mint_amount)?;
+        self._check_public_sale()?;
+        let price = self._current_price()?;
+        self._collect_payment(price, mint_amount, true)?;
+        self._check_phase_supply(mint_amount)?;
         MintingAutoIndexInternal::mint_many(self, Self::env().caller(), mint_amount)?;
+        self._track_phase_minted(mint_amount);
         Ok(())
     }
 
@@ -143,8 +194,281 @@ where
         self._token_uri(token_id)
     }
 
+    /// Get PSP22 token used for mint payments. `None` if paid in native currency.
+    default fn payment_token(&self) -> Option<AccountId> {
+        self.data::<MintingData>().payment_token
+    }
+
+    /// Set PSP22 token used for mint payments. `None` switches to native currency.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_payment_token(&mut self, payment_token: Option<AccountId>) -> Result<()> {
+        self.data::<MintingData>().payment_token = payment_token;
+        Ok(())
+    }
+
     /// Get token mint price.
+    /// If sale phases are configured, the price of the active phase is returned.
     default fn price(&self) -> Balance {
-        self.data::<MintingData>().price_per_mint
+        self._current_price()
+            .unwrap_or(self.data::<MintingData>().price_per_mint)
+    }
+}
+
+impl<T> MintingLimits for T
+where
+    T: Storage<MintingData> + Storage<access_control::Data>,
+{
+    /// Set maximum number of tokens a single account can mint.
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_max_mint_per_wallet(&mut self, limit: Option<u64>) -> Result<()> {
+        self.data::<MintingData>().max_mint_per_wallet = limit;
+        Ok(())
+    }
+
+    /// Set maximum number of tokens that can be minted in a single call.
+    #[modifiers(only_role(CONTRIBUTOR))]
+    default fn set_max_mint_per_call(&mut self, limit: Option<u64>) -> Result<()> {
+        self.data::<MintingData>().max_mint_per_call = limit;
+        Ok(())
+    }
+
+    /// Get maximum number of tokens a single account can mint.
+    default fn max_mint_per_wallet(&self) -> Option<u64> {
+        self.data::<MintingData>().max_mint_per_wallet
+    }
+
+    /// Get maximum number of tokens that can be minted in a single call.
+    default fn max_mint_per_call(&self) -> Option<u64> {
+        self.data::<MintingData>().max_mint_per_call
+    }
+
+    /// Get number of tokens minted by the account.
+    default fn minted_by(&self, account: AccountId) -> u64 {
+        self.data::<MintingData>()
+            .minted_per_account
+            .get(account)
+            .unwrap_or_default()
+    }
+}
+
+impl<T> MintingPhases for T
+where
+    T: Storage<MintingData>
+        + Storage<access_control::Data>
+        + Storage<psp34::Data<enumerable::Balances>>
+        + psp34::extensions::metadata::PSP34Metadata
+        + psp34::Internal,
+{
+    /// Replace the sale phases. Minted counters of the new phases start from zero.
+    /// Phases must not overlap.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_sale_phases(&mut self, sale_phases: Vec<SalePhase>) -> Result<()> {
+        let mut sale_phases = sale_phases;
+        for phase in sale_phases.iter_mut() {
+            if phase.start >= phase.end {
+                return Err(RmrkError::BadConfig.into())
+            }
+            if let Some(floor_price) = phase.floor_price {
+                if floor_price > phase.price {
+                    return Err(RmrkError::BadConfig.into())
+                }
+            }
+            phase.minted = 0;
+        }
+        for (index, phase) in sale_phases.iter().enumerate() {
+            if sale_phases[index + 1..]
+                .iter()
+                .any(|other| phase.start < other.end && other.start < phase.end)
+            {
+                return Err(RmrkError::BadConfig.into())
+            }
+        }
+        self.data::<MintingData>().sale_phases = sale_phases;
+        Ok(())
+    }
+
+    /// Get all configured sale phases.
+    default fn sale_phases(&self) -> Vec<SalePhase> {
+        self.data::<MintingData>().sale_phases.clone()
+    }
+
+    /// Get the sale phase active at the current block timestamp.
+    default fn current_phase(&self) -> Option<SalePhase> {
+        self._active_phase().map(|(_, phase)| phase)
+    }
+}
+
+impl<T> MintingReveal for T
+where
+    T: Storage<MintingData>
+        + Storage<access_control::Data>
+        + Storage<psp34::Data<enumerable::Balances>>
+        + Storage<metadata::Data>
+        + psp34::extensions::metadata::PSP34Metadata
+        + psp34::Internal
+        + Utils,
+{
+    /// Set the URI returned for all tokens until the collection is revealed.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_placeholder_uri(&mut self, uri: PreludeString) -> Result<()> {
+        self.data::<MintingData>().placeholder_uri = Some(uri.into_bytes());
+        Ok(())
+    }
+
+    /// Commit to the final baseUri with its SHA2-256 hash.
+    /// The commitment can be set only once.
+    #[modifiers(only_role(ADMIN))]
+    default fn commit_base_uri(&mut self, commitment: [u8; 32]) -> Result<()> {
+        if self.data::<MintingData>().revealed {
+            return Err(RmrkError::AlreadyRevealed.into())
+        }
+        if self.data::<MintingData>().base_uri_commitment.is_some() {
+            return Err(RmrkError::RevealAlreadyCommitted.into())
+        }
+        self.data::<MintingData>().base_uri_commitment = Some(commitment);
+        Ok(())
+    }
+
+    /// Reveal the collection by setting the committed baseUri.
+    /// The token URI suffix defaults to `.json` on reveal.
+    #[modifiers(only_role(ADMIN))]
+    default fn reveal(&mut self, base_uri: PreludeString) -> Result<()> {
+        if self.data::<MintingData>().revealed {
+            return Err(RmrkError::AlreadyRevealed.into())
+        }
+        let commitment = self
+            .data::<MintingData>()
+            .base_uri_commitment
+            .ok_or(RmrkError::RevealNotCommitted)?;
+
+        let mut hash = [0u8; 32];
+        ink::env::hash_bytes::<ink::env::hash::Sha2x256>(base_uri.as_bytes(), &mut hash);
+        if hash != commitment {
+            return Err(RmrkError::RevealCommitmentMismatch.into())
+        }
+
+        let collection_id = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .collection_id();
+        self._set_attribute(
+            collection_id,
+            String::from("baseUri"),
+            base_uri.into_bytes(),
+        );
+        self.freeze_base_uri();
+        let minting = self.data::<MintingData>();
+        if minting.token_uri_suffix.is_none() {
+            minting.token_uri_suffix = Some(String::from(".json"));
+        }
+        minting.revealed = true;
+        Ok(())
+    }
+
+    /// Check if the collection is revealed.
+    default fn is_revealed(&self) -> bool {
+        self.data::<MintingData>().revealed
+    }
+
+    /// Get the commitment to the final baseUri.
+    default fn base_uri_commitment(&self) -> Option<[u8; 32]> {
+        self.data::<MintingData>().base_uri_commitment
+    }
+}
+
+impl<T> MintingReserved for T
+where
+    T: Storage<MintingData>
+        + MintingAutoIndexInternal
+        + Storage<access_control::Data>
+        + Storage<psp34::Data<enumerable::Balances>>
+        + Storage<extensions::autoindex::MintingAutoIndexData>
+        + psp34::extensions::metadata::PSP34Metadata
+        + psp34::Internal,
+{
+    /// Set the vesting schedule of the reserved supply.
+    /// Reserved supply is the sum of all tranches.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_vesting_schedule(&mut self, schedule: Vec<(Timestamp, u64)>) -> Result<()> {
+        let reserved_supply = schedule
+            .iter()
+            .try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
+            .ok_or(RmrkError::Overflow)?;
+        let minting = self.data::<MintingData>();
+        if reserved_supply < minting.reserved_minted {
+            return Err(RmrkError::BadConfig.into())
+        }
+        let unminted_reserved = (reserved_supply - minting.reserved_minted) as u128;
+        if let Some(max_supply) = minting.max_supply.filter(|max_supply| *max_supply > 0) {
+            let total_supply = self
+                .data::<psp34::Data<enumerable::Balances>>()
+                .balances
+                .total_supply();
+            if total_supply.saturating_add(unminted_reserved) > max_supply as u128 {
+                return Err(RmrkError::BadConfig.into())
+            }
+        }
+
+        let minting = self.data::<MintingData>();
+        minting.reserved_supply = reserved_supply;
+        minting.vesting_schedule = schedule;
+        Ok(())
+    }
+
+    /// Mint unlocked reserved tokens to the specified account.
+    #[modifiers(only_role(ADMIN))]
+    default fn mint_reserved(&mut self, to: AccountId, mint_amount: u64) -> Result<(Id, Id)> {
+        let minting = self.data::<MintingData>();
+        let reserved_minted = minting
+            .reserved_minted
+            .checked_add(mint_amount)
+            .ok_or(RmrkError::Overflow)?;
+        if reserved_minted > minting.reserved_supply {
+            return Err(RmrkError::CollectionIsFull.into())
+        }
+        if reserved_minted > self._unlocked_reserved() {
+            return Err(RmrkError::ReservedSupplyLocked.into())
+        }
+
+        // Release the minted amount from the reserve before minting, so it passes supply checks.
+        // Reserved mints are not subject to public mint limits and don't count towards them.
+        self.data::<MintingData>().reserved_minted = reserved_minted;
+        self._mint_range(to, mint_amount)
+    }
+
+    /// Get the vesting schedule of the reserved supply.
+    default fn vesting_schedule(&self) -> Vec<(Timestamp, u64)> {
+        self.data::<MintingData>().vesting_schedule.clone()
+    }
+
+    /// Get number of reserved tokens not minted yet.
+    default fn remaining_reserved_supply(&self) -> u64 {
+        let minting = self.data::<MintingData>();
+        minting
+            .reserved_supply
+            .saturating_sub(minting.reserved_minted)
+    }
+
+    /// Get number of reserved tokens which are unlocked and not minted yet.
+    default fn unlocked_reserved_supply(&self) -> u64 {
+        self._unlocked_reserved()
+            .min(self.data::<MintingData>().reserved_supply)
+            .saturating_sub(self.data::<MintingData>().reserved_minted)
+    }
+
+    /// Get number of tokens available for public minting. `None` if supply is not limited.
+    default fn remaining_public_supply(&self) -> Option<u64> {
+        let max_supply = self
+            .data::<MintingData>()
+            .max_supply
+            .filter(|max_supply| *max_supply > 0)?;
+        let total_supply = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .balances
+            .total_supply() as u64;
+        Some(
+            max_supply
+                .saturating_sub(total_supply)
+                .saturating_sub(self.remaining_reserved_supply()),
+        )
     }
 }
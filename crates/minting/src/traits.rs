@@ -1,6 +1,9 @@
 //! RMRK minting traits
 
-use rmrk_common::errors::Result;
+use rmrk_common::{
+    errors::Result,
+    types::SalePhase,
+};
 
 use ink::prelude::{
     string::String as PreludeString,
@@ -13,6 +16,7 @@ use openbrush::{
         AccountId,
         Balance,
         String,
+        Timestamp,
     },
 };
 
@@ -25,6 +29,21 @@ pub type MintingLazyRef = dyn MintingLazy;
 #[openbrush::wrapper]
 pub type MintingAutoIndexRef = dyn MintingAutoIndex;
 
+#[openbrush::wrapper]
+pub type MintingLimitsRef = dyn MintingLimits;
+
+#[openbrush::wrapper]
+pub type MintingPhasesRef = dyn MintingPhases;
+
+#[openbrush::wrapper]
+pub type MintingReservedRef = dyn MintingReserved;
+
+#[openbrush::wrapper]
+pub type MintingRevealRef = dyn MintingReveal;
+
+#[openbrush::wrapper]
+pub type MintingAllowlistRef = dyn MintingAllowlist;
+
 /// Trait definitions for core Minting functions
 #[openbrush::trait_definition]
 pub trait Minting {
@@ -40,6 +59,10 @@ pub trait Minting {
     #[ink(message)]
     fn assign_metadata(&mut self, token_id: Id, metadata: String) -> Result<()>;
 
+    /// Set suffix appended to token URIs derived from the baseUri, e.g. `.json`.
+    #[ink(message)]
+    fn set_token_uri_suffix(&mut self, suffix: Option<String>) -> Result<()>;
+
     /// Get max supply of tokens.
     #[ink(message)]
     fn max_supply(&self) -> Option<u64>;
@@ -64,6 +87,14 @@ pub trait MintingLazy {
     #[ink(message)]
     fn price(&self) -> Balance;
 
+    /// Get PSP22 token used for mint payments. `None` if paid in native currency.
+    #[ink(message)]
+    fn payment_token(&self) -> Option<AccountId>;
+
+    /// Set PSP22 token used for mint payments. `None` switches to native currency.
+    #[ink(message)]
+    fn set_payment_token(&mut self, payment_token: Option<AccountId>) -> Result<()>;
+
     /// Get max supply of tokens.
     #[ink(message)]
     fn max_supply(&self) -> Option<u64>;
@@ -84,3 +115,132 @@ pub trait MintingAutoIndex {
     #[ink(message)]
     fn mint_many(&mut self, to: AccountId, mint_amount: u64) -> Result<(Id, Id)>;
 }
+
+/// Trait definitions for Minting limits
+#[openbrush::trait_definition]
+pub trait MintingLimits {
+    /// Set maximum number of tokens a single account can mint.
+    /// `None` removes the limit.
+    #[ink(message)]
+    fn set_max_mint_per_wallet(&mut self, limit: Option<u64>) -> Result<()>;
+
+    /// Set maximum number of tokens that can be minted in a single call.
+    /// `None` removes the limit.
+    #[ink(message)]
+    fn set_max_mint_per_call(&mut self, limit: Option<u64>) -> Result<()>;
+
+    /// Get maximum number of tokens a single account can mint.
+    #[ink(message)]
+    fn max_mint_per_wallet(&self) -> Option<u64>;
+
+    /// Get maximum number of tokens that can be minted in a single call.
+    #[ink(message)]
+    fn max_mint_per_call(&self) -> Option<u64>;
+
+    /// Get number of tokens minted by the account.
+    #[ink(message)]
+    fn minted_by(&self, account: AccountId) -> u64;
+}
+
+/// Trait definitions for sale phases of lazy Minting
+#[openbrush::trait_definition]
+pub trait MintingPhases {
+    /// Replace the sale phases.
+    #[ink(message)]
+    fn set_sale_phases(&mut self, sale_phases: Vec<SalePhase>) -> Result<()>;
+
+    /// Get all configured sale phases.
+    #[ink(message)]
+    fn sale_phases(&self) -> Vec<SalePhase>;
+
+    /// Get the sale phase active at the current block timestamp.
+    #[ink(message)]
+    fn current_phase(&self) -> Option<SalePhase>;
+}
+
+/// Trait definitions for reserved (team) Minting functions
+#[openbrush::trait_definition]
+pub trait MintingReserved {
+    /// Set the vesting schedule of the reserved supply as `(unlock_time, amount)` tranches.
+    /// Reserved supply is the sum of all tranches and is excluded from other mints.
+    #[ink(message)]
+    fn set_vesting_schedule(&mut self, schedule: Vec<(Timestamp, u64)>) -> Result<()>;
+
+    /// Mint unlocked reserved tokens to the specified account, with auto-generated Ids.
+    /// The returned range provides the first and last generated Id
+    #[ink(message)]
+    fn mint_reserved(&mut self, to: AccountId, mint_amount: u64) -> Result<(Id, Id)>;
+
+    /// Get the vesting schedule of the reserved supply.
+    #[ink(message)]
+    fn vesting_schedule(&self) -> Vec<(Timestamp, u64)>;
+
+    /// Get number of reserved tokens not minted yet.
+    #[ink(message)]
+    fn remaining_reserved_supply(&self) -> u64;
+
+    /// Get number of reserved tokens which are unlocked and not minted yet.
+    #[ink(message)]
+    fn unlocked_reserved_supply(&self) -> u64;
+
+    /// Get number of tokens available for public minting. `None` if supply is not limited.
+    #[ink(message)]
+    fn remaining_public_supply(&self) -> Option<u64>;
+}
+
+/// Trait definitions for delayed reveal of token metadata
+#[openbrush::trait_definition]
+pub trait MintingReveal {
+    /// Set the URI returned for all tokens until the collection is revealed.
+    #[ink(message)]
+    fn set_placeholder_uri(&mut self, uri: PreludeString) -> Result<()>;
+
+    /// Commit to the final baseUri with its SHA2-256 hash.
+    /// The commitment can be set only once.
+    #[ink(message)]
+    fn commit_base_uri(&mut self, commitment: [u8; 32]) -> Result<()>;
+
+    /// Reveal the collection by setting the committed baseUri.
+    /// Token URIs are then derived as `baseUri + id + suffix`,
+    /// with `.json` as the suffix unless another one is set.
+    /// The baseUri can no longer be changed after reveal.
+    #[ink(message)]
+    fn reveal(&mut self, base_uri: PreludeString) -> Result<()>;
+
+    /// Check if the collection is revealed.
+    #[ink(message)]
+    fn is_revealed(&self) -> bool;
+
+    /// Get the commitment to the final baseUri.
+    #[ink(message)]
+    fn base_uri_commitment(&self) -> Option<[u8; 32]>;
+}
+
+/// Trait definitions for allowlist (presale) Minting functions
+#[openbrush::trait_definition]
+pub trait MintingAllowlist {
+    /// Add account to the allowlist, or update its quota and presale price.
+    #[ink(message)]
+    fn add_to_allowlist(&mut self, account: AccountId, quota: u64, price: Balance) -> Result<()>;
+
+    /// Remove account from the allowlist.
+    #[ink(message)]
+    fn remove_from_allowlist(&mut self, account: AccountId) -> Result<()>;
+
+    /// Set the timestamp from which public minting is open.
+    /// `None` opens public minting immediately.
+    #[ink(message)]
+    fn set_public_sale_start(&mut self, start: Option<Timestamp>) -> Result<()>;
+
+    /// Purchase tokens at the presale price, within the caller's allowlist quota.
+    #[ink(message, payable)]
+    fn presale_mint(&mut self, mint_amount: u64) -> Result<()>;
+
+    /// Get remaining quota and presale price for the account, if allowlisted.
+    #[ink(message)]
+    fn allowlist_entry(&self, account: AccountId) -> Option<(u64, Balance)>;
+
+    /// Get the timestamp from which public minting is open.
+    #[ink(message)]
+    fn public_sale_start(&self) -> Option<Timestamp>;
+}
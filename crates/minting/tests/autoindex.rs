@@ -122,6 +122,16 @@ pub mod rmrk_contract_minting {
             Balance,
         };
 
+        use rmrk_common::{
+            counter::{
+                Counter,
+                HashedIdGenerator,
+                IdGenerator,
+                PrefixedIdGenerator,
+            },
+            errors::RmrkError,
+        };
+
         use crate::common::{
             check_mint_many_outcome,
             check_mint_single_outcome,
@@ -168,5 +178,44 @@ pub mod rmrk_contract_minting {
 
             check_mint_many_outcome(rmrk, accounts.bob, 5);
         }
+
+        #[ink::test]
+        fn autoindex_id_variant_works() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(
+                Counter::new(Id::Bytes(vec![0])).err(),
+                Some(RmrkError::UnsupportedIdVariant.into())
+            );
+            rmrk.minting_autoindex.token_id = Counter::new(Id::U32(0)).unwrap();
+            assert_eq!(
+                MintingAutoIndex::mint_many(&mut rmrk, accounts.bob, 2),
+                Ok((Id::U32(1), Id::U32(2)))
+            );
+
+            rmrk.minting_autoindex.token_id = Counter::new(Id::U8(u8::MAX)).unwrap();
+            assert_eq!(
+                MintingAutoIndex::mint(&mut rmrk, accounts.bob),
+                Err(RmrkError::Overflow.into())
+            );
+        }
+
+        #[ink::test]
+        fn id_generators_work() {
+            let accounts = default_accounts();
+            let mut prefixed = PrefixedIdGenerator::new(b"badge-".to_vec());
+            assert_eq!(prefixed.next_id(), Ok(Id::Bytes(b"badge-1".to_vec())));
+            assert_eq!(prefixed.next_id(), Ok(Id::Bytes(b"badge-2".to_vec())));
+
+            let mut hashed = HashedIdGenerator::new(accounts.alice);
+            let first = hashed.next_id().unwrap();
+            let second = hashed.next_id().unwrap();
+            assert!(matches!(&first, Id::Bytes(hash) if hash.len() == 32));
+            assert_ne!(first, second);
+            assert_ne!(
+                HashedIdGenerator::new(accounts.bob).next_id().unwrap(),
+                first
+            );
+        }
     }
 }
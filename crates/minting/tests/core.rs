@@ -80,6 +80,10 @@ pub mod rmrk_contract_minting {
 
     impl Minting for Rmrk {}
 
+    impl MintingLimits for Rmrk {}
+
+    impl MintingReveal for Rmrk {}
+
     impl Rmrk {
         #[allow(clippy::too_many_arguments)]
         #[ink(constructor)]
@@ -137,9 +141,14 @@ pub mod rmrk_contract_minting {
                 ADMIN,
                 CONTRIBUTOR,
             },
+            utils::Utils,
         };
 
-        use rmrk_minting::traits::Minting;
+        use rmrk_minting::traits::{
+            Minting,
+            MintingLimits,
+            MintingReveal,
+        };
 
         use openbrush::contracts::{
             access_control::*,
@@ -331,8 +340,11 @@ pub mod rmrk_contract_minting {
             assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
             // return error if request is for not yet minted token
             assert_eq!(rmrk.token_uri(Id::U64(42)), Err(PSP34Error::TokenNotExists.into()));
-            // return error if metadata is net yet assigned
-            assert_eq!(rmrk.token_uri(Id::U64(1)), Err(RmrkError::UriNotFound.into()));
+            // fall back to baseUri if metadata is not yet assigned
+            assert_eq!(
+                rmrk.token_uri(Id::U64(1)),
+                Ok(PreludeString::from("ipfs://myIpfsUri/1"))
+            );
 
             assert!(rmrk
                 .assign_metadata(Id::U64(1), String::from(RMRK_METADATA))
@@ -340,5 +352,156 @@ pub mod rmrk_contract_minting {
 
             assert_eq!(rmrk.token_uri(Id::U64(1)), Ok(PreludeString::from(RMRK_METADATA)));
         }
+
+        #[ink::test]
+        fn mint_limits_apply_to_explicit_ids() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(rmrk.set_max_mint_per_call(Some(2)), Ok(()));
+            assert_eq!(rmrk.set_max_mint_per_wallet(Some(3)), Ok(()));
+
+            assert_eq!(
+                rmrk.mint_many(accounts.bob, vec![Id::U64(1), Id::U64(2), Id::U64(3)]),
+                Err(RmrkError::MintPerCallLimitExceeded.into())
+            );
+            assert_eq!(
+                rmrk.mint_many(accounts.bob, vec![Id::U64(1), Id::U64(2)]),
+                Ok(())
+            );
+            assert_eq!(rmrk.mint(accounts.bob, Id::U64(3)), Ok(()));
+            assert_eq!(
+                rmrk.mint(accounts.bob, Id::U64(4)),
+                Err(RmrkError::MintPerWalletLimitExceeded.into())
+            );
+            assert_eq!(rmrk.minted_by(accounts.bob), 3);
+        }
+
+        #[ink::test]
+        fn delayed_reveal_works() {
+            const PLACEHOLDER_URI: &str = "ipfs://placeholder.json";
+            const REVEALED_URI: &str = "ipfs://revealedUri/";
+
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(rmrk.mint(accounts.alice, Id::U64(1)), Ok(()));
+            assert_eq!(rmrk.mint(accounts.alice, Id::U64(2)), Ok(()));
+            assert_eq!(
+                rmrk.set_placeholder_uri(PreludeString::from(PLACEHOLDER_URI)),
+                Ok(())
+            );
+            assert!(!rmrk.is_revealed());
+            assert_eq!(
+                rmrk.token_uri(Id::U64(1)),
+                Ok(PreludeString::from(PLACEHOLDER_URI))
+            );
+
+            // Reveal requires a commitment
+            assert_eq!(
+                rmrk.reveal(PreludeString::from(REVEALED_URI)),
+                Err(RmrkError::RevealNotCommitted.into())
+            );
+
+            let mut commitment = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Sha2x256>(
+                REVEALED_URI.as_bytes(),
+                &mut commitment,
+            );
+            assert_eq!(rmrk.commit_base_uri(commitment), Ok(()));
+            assert_eq!(rmrk.base_uri_commitment(), Some(commitment));
+
+            // Commitment can't be replaced
+            assert_eq!(
+                rmrk.commit_base_uri([0u8; 32]),
+                Err(RmrkError::RevealAlreadyCommitted.into())
+            );
+
+            // Reveal with a different baseUri fails
+            assert_eq!(
+                rmrk.reveal(PreludeString::from(BASE_URI)),
+                Err(RmrkError::RevealCommitmentMismatch.into())
+            );
+
+            // Only admin can reveal
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.reveal(PreludeString::from(REVEALED_URI)),
+                Err(AccessControlError::MissingRole.into())
+            );
+
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.reveal(PreludeString::from(REVEALED_URI)), Ok(()));
+            assert!(rmrk.is_revealed());
+            assert_eq!(
+                rmrk.token_uri(Id::U64(1)),
+                Ok(PreludeString::from("ipfs://revealedUri/1.json"))
+            );
+
+            // Suffix can still be changed after reveal
+            assert_eq!(rmrk.set_token_uri_suffix(Some(String::new())), Ok(()));
+            assert_eq!(
+                rmrk.token_uri(Id::U64(1)),
+                Ok(PreludeString::from("ipfs://revealedUri/1"))
+            );
+
+            // Explicit token URI still wins
+            assert!(rmrk
+                .assign_metadata(Id::U64(2), String::from(RMRK_METADATA))
+                .is_ok());
+            assert_eq!(
+                rmrk.token_uri(Id::U64(2)),
+                Ok(PreludeString::from(RMRK_METADATA))
+            );
+
+            assert_eq!(
+                rmrk.reveal(PreludeString::from(REVEALED_URI)),
+                Err(RmrkError::AlreadyRevealed.into())
+            );
+
+            // baseUri is frozen after reveal
+            assert_eq!(
+                rmrk.set_base_uri(PreludeString::from(BASE_URI)),
+                Err(RmrkError::BaseUriFrozen.into())
+            );
+            assert_eq!(
+                rmrk.token_uri(Id::U64(1)),
+                Ok(PreludeString::from("ipfs://revealedUri/1"))
+            );
+        }
+
+        #[ink::test]
+        fn derived_token_uri_works_for_all_id_variants() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            let ids = [
+                (Id::U8(1), "ipfs://myIpfsUri/1"),
+                (Id::U16(2), "ipfs://myIpfsUri/2"),
+                (Id::U32(3), "ipfs://myIpfsUri/3"),
+                (Id::U64(4), "ipfs://myIpfsUri/4"),
+                (Id::U128(5), "ipfs://myIpfsUri/5"),
+                (Id::Bytes(b"token".to_vec()), "ipfs://myIpfsUri/token"),
+                (Id::Bytes(vec![0xff, 0x01]), "ipfs://myIpfsUri/ff01"),
+            ];
+
+            for (id, uri) in ids {
+                assert_eq!(rmrk.mint(accounts.alice, id.clone()), Ok(()));
+                assert_eq!(rmrk.token_uri(id), Ok(PreludeString::from(uri)));
+            }
+
+            assert_eq!(
+                rmrk.set_token_uri_suffix(Some(String::from(".json"))),
+                Ok(())
+            );
+            assert_eq!(
+                rmrk.token_uri(Id::U8(1)),
+                Ok(PreludeString::from("ipfs://myIpfsUri/1.json"))
+            );
+
+            // Only contributor can set the suffix
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_token_uri_suffix(None),
+                Err(AccessControlError::MissingRole.into())
+            );
+        }
     }
 }
@@ -22,9 +22,16 @@ pub mod rmrk_contract_minting {
         EmitEvent,
         Env,
     };
-    use rmrk_common::roles::CONTRIBUTOR;
+    use rmrk_common::{
+        errors::Result,
+        roles::CONTRIBUTOR,
+        splitter::*,
+    };
     use rmrk_minting::{
-        extensions::autoindex::*,
+        extensions::{
+            allowlist::*,
+            autoindex::*,
+        },
         traits::*,
         MintingData,
     };
@@ -66,6 +73,10 @@ pub mod rmrk_contract_minting {
         minting: MintingData,
         #[storage_field]
         minting_autoindex: MintingAutoIndexData,
+        #[storage_field]
+        minting_allowlist: MintingAllowlistData,
+        #[storage_field]
+        splitter: PaymentSplitterData,
     }
 
     impl PSP34 for Rmrk {}
@@ -78,6 +89,27 @@ pub mod rmrk_contract_minting {
 
     impl MintingLazy for Rmrk {}
 
+    impl MintingAllowlist for Rmrk {}
+
+    impl MintingLimits for Rmrk {}
+
+    impl MintingPhases for Rmrk {}
+
+    impl MintingReserved for Rmrk {}
+
+    impl PaymentSplitter for Rmrk {}
+
+    impl RevenueHooks for Rmrk {
+        /// Record the mint payments for the payees
+        fn _on_revenue_received(
+            &mut self,
+            token: Option<AccountId>,
+            amount: Balance,
+        ) -> Result<()> {
+            self.record_revenue(token, amount)
+        }
+    }
+
     impl Rmrk {
         #[allow(clippy::too_many_arguments)]
         #[ink(constructor)]
@@ -140,9 +172,17 @@ pub mod rmrk_contract_minting {
         use rmrk_common::{
             errors::RmrkError,
             roles::ADMIN,
+            splitter::PaymentSplitter,
+            types::SalePhase,
             utils::Utils,
         };
-        use rmrk_minting::traits::MintingLazy;
+        use rmrk_minting::traits::{
+            MintingAllowlist,
+            MintingLazy,
+            MintingLimits,
+            MintingPhases,
+            MintingReserved,
+        };
 
         use crate::common::{
             check_mint_many_outcome,
@@ -154,6 +194,7 @@ pub mod rmrk_contract_minting {
         };
 
         pub const PRICE: Balance = 100_000_000_000_000_000;
+        pub const PRESALE_PRICE: Balance = 50_000_000_000_000_000;
 
         impl Accessor for super::Rmrk {
             fn _owners_token_by_index(
@@ -177,6 +218,11 @@ pub mod rmrk_contract_minting {
             ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account_id, balance)
         }
 
+        fn get_balance(account_id: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account_id)
+                .expect("Cannot get account balance")
+        }
+
         #[ink::test]
         fn init_with_price_works() {
             let rmrk = init();
@@ -200,6 +246,12 @@ pub mod rmrk_contract_minting {
             purchase(num_of_mints - 1);
             assert_eq!(rmrk.mint(), Err(RmrkError::BadMintValue.into()));
             assert_eq!(rmrk.total_supply(), 0);
+
+            // Only admin can change the payment token
+            assert_eq!(
+                rmrk.set_payment_token(None),
+                Err(AccessControlError::MissingRole.into())
+            );
         }
 
         #[ink::test]
@@ -224,6 +276,44 @@ pub mod rmrk_contract_minting {
             // assert_eq!(rmrk.env().balance(), rmrk.env().minimum_balance());
         }
 
+        #[ink::test]
+        fn revenue_is_split_between_payees() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(
+                rmrk.set_payees(vec![(accounts.charlie, 1), (accounts.django, 3)]),
+                Ok(())
+            );
+            set_balance(accounts.bob, PRICE * 4);
+            set_sender(accounts.bob);
+            assert!(pay_with_call!(rmrk.mint_many(4), PRICE * 4).is_ok());
+            assert_eq!(rmrk.total_received(), PRICE * 4);
+            assert_eq!(rmrk.pending_payment(accounts.django), PRICE * 3);
+
+            // Anyone can release the payment to the payee
+            let charlie_balance = get_balance(accounts.charlie);
+            assert_eq!(rmrk.release(accounts.charlie), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_balance + PRICE);
+            assert_eq!(
+                rmrk.release(accounts.charlie),
+                Err(RmrkError::NoPaymentDue.into())
+            );
+
+            // Payment due to django stays owed after the payees are replaced
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.set_payees(vec![(accounts.eve, 1)]), Ok(()));
+            assert_eq!(rmrk.total_received(), 0);
+            assert_eq!(rmrk.pending_payment(accounts.eve), 0);
+            assert_eq!(rmrk.pending_payment(accounts.django), PRICE * 3);
+            let django_balance = get_balance(accounts.django);
+            assert_eq!(rmrk.release(accounts.django), Ok(()));
+            assert_eq!(get_balance(accounts.django), django_balance + PRICE * 3);
+            assert_eq!(
+                rmrk.release(accounts.django),
+                Err(RmrkError::AccountHasNoShares.into())
+            );
+        }
+
         #[ink::test]
         fn mint_single_lazy_works() {
             let mut rmrk = init();
@@ -336,5 +426,376 @@ pub mod rmrk_contract_minting {
             assert_eq!(rmrk.mint_many(num_of_mints), Ok(()));
             check_mint_many_outcome(rmrk, accounts.bob, num_of_mints);
         }
+
+        #[ink::test]
+        fn presale_mint_works() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(
+                rmrk.add_to_allowlist(accounts.bob, 3, PRESALE_PRICE),
+                Ok(())
+            );
+            assert_eq!(rmrk.allowlist_entry(accounts.bob), Some((3, PRESALE_PRICE)));
+
+            set_sender(accounts.bob);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(PRESALE_PRICE * 2);
+            assert_eq!(rmrk.presale_mint(2), Ok(()));
+            assert_eq!(rmrk.allowlist_entry(accounts.bob), Some((1, PRESALE_PRICE)));
+            assert_eq!(rmrk.balance_of(accounts.bob), 2);
+
+            // Presale price is enforced
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(PRICE);
+            assert_eq!(rmrk.presale_mint(1), Err(RmrkError::BadMintValue.into()));
+
+            // Quota is enforced
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(PRESALE_PRICE * 2);
+            assert_eq!(
+                rmrk.presale_mint(2),
+                Err(RmrkError::AllowlistQuotaExceeded.into())
+            );
+        }
+
+        #[ink::test]
+        fn presale_mint_not_allowlisted_fails() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(
+                rmrk.add_to_allowlist(accounts.bob, 1, PRESALE_PRICE),
+                Ok(())
+            );
+            assert_eq!(rmrk.remove_from_allowlist(accounts.bob), Ok(()));
+
+            set_sender(accounts.bob);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(PRESALE_PRICE);
+            assert_eq!(rmrk.presale_mint(1), Err(RmrkError::NotAllowlisted.into()));
+        }
+
+        #[ink::test]
+        fn allowlist_management_requires_admin() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.add_to_allowlist(accounts.bob, 1, PRESALE_PRICE),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_public_sale_start(Some(1)),
+                Err(AccessControlError::MissingRole.into())
+            );
+        }
+
+        #[ink::test]
+        fn public_mint_gated_until_sale_start() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(rmrk.set_public_sale_start(Some(1_000)), Ok(()));
+            assert_eq!(rmrk.public_sale_start(), Some(1_000));
+
+            set_sender(accounts.bob);
+            purchase(1);
+            assert_eq!(rmrk.mint(), Err(RmrkError::PublicSaleNotStarted.into()));
+            assert_eq!(
+                rmrk.mint_many(1),
+                Err(RmrkError::PublicSaleNotStarted.into())
+            );
+
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            assert_eq!(rmrk.mint(), Ok(()));
+        }
+
+        #[ink::test]
+        fn mint_per_call_limit_works() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(rmrk.set_max_mint_per_call(Some(2)), Ok(()));
+            assert_eq!(rmrk.max_mint_per_call(), Some(2));
+
+            set_sender(accounts.bob);
+            purchase(3);
+            assert_eq!(
+                rmrk.mint_many(3),
+                Err(RmrkError::MintPerCallLimitExceeded.into())
+            );
+            purchase(2);
+            assert_eq!(rmrk.mint_many(2), Ok(()));
+            purchase(2);
+            assert_eq!(rmrk.mint_many(2), Ok(()));
+            assert_eq!(rmrk.minted_by(accounts.bob), 4);
+        }
+
+        #[ink::test]
+        fn mint_per_wallet_limit_works() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(rmrk.set_max_mint_per_wallet(Some(3)), Ok(()));
+            assert_eq!(rmrk.max_mint_per_wallet(), Some(3));
+
+            set_sender(accounts.bob);
+            purchase(2);
+            assert_eq!(rmrk.mint_many(2), Ok(()));
+            purchase(2);
+            assert_eq!(
+                rmrk.mint_many(2),
+                Err(RmrkError::MintPerWalletLimitExceeded.into())
+            );
+            purchase(1);
+            assert_eq!(rmrk.mint(), Ok(()));
+            purchase(1);
+            assert_eq!(
+                rmrk.mint(),
+                Err(RmrkError::MintPerWalletLimitExceeded.into())
+            );
+            assert_eq!(rmrk.minted_by(accounts.bob), 3);
+
+            // Limit is tracked per account
+            set_sender(accounts.charlie);
+            purchase(3);
+            assert_eq!(rmrk.mint_many(3), Ok(()));
+        }
+
+        #[ink::test]
+        fn set_mint_limits_requires_contributor() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_max_mint_per_wallet(Some(1)),
+                Err(AccessControlError::MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.set_max_mint_per_call(Some(1)),
+                Err(AccessControlError::MissingRole.into())
+            );
+        }
+        fn set_timestamp(timestamp: u64) {
+            test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        fn phase(start: u64, end: u64, price: Balance, supply: Option<u64>) -> SalePhase {
+            SalePhase {
+                start,
+                end,
+                price,
+                floor_price: None,
+                supply,
+                minted: 0,
+            }
+        }
+
+        #[ink::test]
+        fn sale_phases_pricing_works() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(
+                rmrk.set_sale_phases(vec![
+                    phase(100, 200, PRICE / 2, Some(2)),
+                    phase(200, 300, PRICE, None),
+                ]),
+                Ok(())
+            );
+            assert_eq!(rmrk.sale_phases().len(), 2);
+
+            set_sender(accounts.bob);
+
+            // No phase is active yet
+            assert_eq!(rmrk.current_phase(), None);
+            purchase(1);
+            assert_eq!(rmrk.mint(), Err(RmrkError::NoActiveSalePhase.into()));
+
+            // First phase
+            set_timestamp(100);
+            assert_eq!(rmrk.price(), PRICE / 2);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(PRICE / 2 * 3);
+            assert_eq!(
+                rmrk.mint_many(3),
+                Err(RmrkError::SalePhaseSupplyExceeded.into())
+            );
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(PRICE / 2 * 2);
+            assert_eq!(rmrk.mint_many(2), Ok(()));
+            assert_eq!(rmrk.current_phase().map(|phase| phase.minted), Some(2));
+
+            // Second phase
+            set_timestamp(250);
+            assert_eq!(rmrk.price(), PRICE);
+            purchase(1);
+            assert_eq!(rmrk.mint(), Ok(()));
+            assert_eq!(rmrk.total_supply(), 3);
+
+            // All phases are over
+            set_timestamp(300);
+            assert_eq!(rmrk.mint(), Err(RmrkError::NoActiveSalePhase.into()));
+        }
+
+        #[ink::test]
+        fn dutch_auction_phase_works() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            let mut auction = phase(0, 100, PRICE, None);
+            auction.floor_price = Some(PRICE / 2);
+            assert_eq!(rmrk.set_sale_phases(vec![auction]), Ok(()));
+
+            assert_eq!(rmrk.price(), PRICE);
+            set_timestamp(50);
+            assert_eq!(rmrk.price(), PRICE * 3 / 4);
+            set_timestamp(99);
+            assert!(rmrk.price() > PRICE / 2);
+
+            set_sender(accounts.bob);
+            set_timestamp(50);
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(PRICE * 3 / 4);
+            assert_eq!(rmrk.mint(), Ok(()));
+        }
+
+        #[ink::test]
+        fn dutch_auction_excess_value_is_refunded() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            let mut auction = phase(0, 100, PRICE, None);
+            auction.floor_price = Some(PRICE / 2);
+            assert_eq!(rmrk.set_sale_phases(vec![auction]), Ok(()));
+
+            // Bob pays the start price, the price dropped by the time the mint is included
+            set_balance(accounts.bob, PRICE * 2);
+            set_sender(accounts.bob);
+            set_timestamp(50);
+            assert!(pay_with_call!(rmrk.mint(), PRICE).is_ok());
+            assert_eq!(rmrk.balance_of(accounts.bob), 1);
+            assert_eq!(
+                rmrk.env().balance(),
+                PRICE * 3 / 4 + rmrk.env().minimum_balance()
+            );
+        }
+
+        #[ink::test]
+        fn set_sale_phases_validation_works() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(
+                rmrk.set_sale_phases(vec![phase(100, 100, PRICE, None)]),
+                Err(RmrkError::BadConfig.into())
+            );
+            let mut auction = phase(0, 100, PRICE, None);
+            auction.floor_price = Some(PRICE * 2);
+            assert_eq!(
+                rmrk.set_sale_phases(vec![auction]),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(
+                rmrk.set_sale_phases(vec![
+                    phase(0, 100, PRICE, None),
+                    phase(50, 150, PRICE, None)
+                ]),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert_eq!(
+                rmrk.set_sale_phases(vec![
+                    phase(100, 200, PRICE, None),
+                    phase(0, 100, PRICE, None)
+                ]),
+                Ok(())
+            );
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_sale_phases(vec![]),
+                Err(AccessControlError::MissingRole.into())
+            );
+        }
+
+        #[ink::test]
+        fn mint_with_native_value_fails_for_psp22_payment() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(rmrk.set_payment_token(Some(accounts.django)), Ok(()));
+            assert_eq!(rmrk.payment_token(), Some(accounts.django));
+
+            set_sender(accounts.bob);
+            purchase(1);
+            assert_eq!(rmrk.mint(), Err(RmrkError::BadMintValue.into()));
+            assert_eq!(rmrk.total_supply(), 0);
+        }
+
+        #[ink::test]
+        fn withdraw_psp22_requires_admin() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.withdraw_psp22(accounts.django),
+                Err(AccessControlError::MissingRole.into())
+            );
+        }
+
+        #[ink::test]
+        fn reserved_supply_with_vesting_works() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(rmrk.set_vesting_schedule(vec![(0, 2), (1_000, 2)]), Ok(()));
+            assert_eq!(rmrk.remaining_reserved_supply(), 4);
+            assert_eq!(rmrk.unlocked_reserved_supply(), 2);
+            assert_eq!(rmrk.remaining_public_supply(), Some(MAX_SUPPLY - 4));
+
+            // Public mint can't take reserved supply
+            set_sender(accounts.bob);
+            purchase(MAX_SUPPLY - 3);
+            assert_eq!(
+                rmrk.mint_many(MAX_SUPPLY - 3),
+                Err(RmrkError::CollectionIsFull.into())
+            );
+            purchase(MAX_SUPPLY - 4);
+            assert_eq!(rmrk.mint_many(MAX_SUPPLY - 4), Ok(()));
+            assert_eq!(rmrk.remaining_public_supply(), Some(0));
+
+            // Only admin can mint reserved supply
+            assert_eq!(
+                rmrk.mint_reserved(accounts.bob, 1),
+                Err(AccessControlError::MissingRole.into())
+            );
+
+            // Only unlocked tranches can be minted
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.mint_reserved(accounts.charlie, 3),
+                Err(RmrkError::ReservedSupplyLocked.into())
+            );
+            assert!(rmrk.mint_reserved(accounts.charlie, 2).is_ok());
+            assert_eq!(rmrk.unlocked_reserved_supply(), 0);
+
+            set_timestamp(1_000);
+            assert_eq!(rmrk.unlocked_reserved_supply(), 2);
+            assert!(rmrk.mint_reserved(accounts.charlie, 2).is_ok());
+            assert_eq!(rmrk.remaining_reserved_supply(), 0);
+            assert_eq!(rmrk.total_supply(), MAX_SUPPLY as u128);
+        }
+
+        #[ink::test]
+        fn reserved_mint_skips_public_limits() {
+            let mut rmrk = init();
+            let accounts = default_accounts();
+            assert_eq!(rmrk.set_vesting_schedule(vec![(0, 3)]), Ok(()));
+            assert_eq!(rmrk.set_max_mint_per_call(Some(1)), Ok(()));
+            assert_eq!(rmrk.set_max_mint_per_wallet(Some(1)), Ok(()));
+
+            assert!(rmrk.mint_reserved(accounts.bob, 3).is_ok());
+            assert_eq!(rmrk.minted_by(accounts.bob), 0);
+            assert_eq!(rmrk.remaining_reserved_supply(), 0);
+
+            // Public mint quota of the account is untouched
+            set_sender(accounts.bob);
+            purchase(1);
+            assert_eq!(rmrk.mint(), Ok(()));
+            assert_eq!(rmrk.minted_by(accounts.bob), 1);
+            assert_eq!(rmrk.total_supply(), 4);
+        }
+
+        #[ink::test]
+        fn set_vesting_schedule_above_max_supply_fails() {
+            let mut rmrk = init();
+            assert_eq!(
+                rmrk.set_vesting_schedule(vec![(0, MAX_SUPPLY + 1)]),
+                Err(RmrkError::BadConfig.into())
+            );
+        }
     }
 }
@@ -0,0 +1,114 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod rmrk_contract_minting_prefixed {
+
+    use ink::prelude::vec::Vec;
+    use openbrush::{
+        contracts::{
+            access_control::*,
+            psp34::extensions::{
+                enumerable::*,
+                metadata::*,
+            },
+            reentrancy_guard::*,
+        },
+        traits::Storage,
+    };
+
+    use rmrk_common::{
+        counter::{
+            IdGenerator,
+            PrefixedIdGenerator,
+        },
+        errors::Result,
+        roles::CONTRIBUTOR,
+    };
+    use rmrk_minting::{
+        extensions::autoindex::*,
+        traits::*,
+        MintingData,
+    };
+
+    // Rmrk contract storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Rmrk {
+        #[storage_field]
+        psp34: psp34::Data<enumerable::Balances>,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        #[storage_field]
+        minting: MintingData,
+        #[storage_field]
+        minting_autoindex: MintingAutoIndexData,
+        id_generator: PrefixedIdGenerator,
+    }
+
+    impl PSP34 for Rmrk {}
+
+    impl AccessControl for Rmrk {}
+
+    impl PSP34Metadata for Rmrk {}
+
+    impl PSP34Enumerable for Rmrk {}
+
+    impl MintingAutoIndex for Rmrk {}
+
+    impl MintingAutoIndexInternal for Rmrk {
+        /// Generate `Id::Bytes` ids from the prefix instead of the `Id` counter
+        fn _next_token_id(&mut self) -> Result<Id> {
+            self.id_generator.next_id()
+        }
+    }
+
+    impl Rmrk {
+        #[ink(constructor)]
+        pub fn new(max_supply: Option<u64>, prefix: Vec<u8>) -> Self {
+            let mut instance = Rmrk::default();
+            instance._init_with_admin(instance.env().caller());
+            instance._setup_role(CONTRIBUTOR, instance.env().caller());
+            instance.minting.max_supply = max_supply;
+            instance.id_generator = PrefixedIdGenerator::new(prefix);
+            instance
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+        use rmrk_common::errors::RmrkError;
+
+        #[ink::test]
+        fn prefixed_id_generator_mint_works() {
+            let mut rmrk = Rmrk::new(Some(3), b"badge-".to_vec());
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(
+                MintingAutoIndex::mint(&mut rmrk, accounts.bob),
+                Ok(Id::Bytes(b"badge-1".to_vec()))
+            );
+            assert_eq!(
+                MintingAutoIndex::mint_many(&mut rmrk, accounts.bob, 2),
+                Ok((
+                    Id::Bytes(b"badge-2".to_vec()),
+                    Id::Bytes(b"badge-3".to_vec())
+                ))
+            );
+            assert_eq!(rmrk.total_supply(), 3);
+            assert_eq!(
+                rmrk.owner_of(Id::Bytes(b"badge-3".to_vec())),
+                Some(accounts.bob)
+            );
+            assert_eq!(
+                MintingAutoIndex::mint(&mut rmrk, accounts.bob),
+                Err(RmrkError::CollectionIsFull.into())
+            );
+        }
+    }
+}
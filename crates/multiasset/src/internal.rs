@@ -9,14 +9,20 @@ use rmrk_common::{
         RmrkError,
     },
     types::*,
-    utils::Utils,
+    utils::{
+        OwnershipHooks,
+        Utils,
+    },
 };
 
 use ink::prelude::vec::Vec;
 
 use openbrush::{
     contracts::psp34::extensions::enumerable::*,
-    traits::Storage,
+    traits::{
+        AccountId,
+        Storage,
+    },
 };
 
 /// Trait definitions for MultiAsset helper functions
@@ -30,6 +36,10 @@ pub trait Internal {
     /// Check if asset is already pending. Return OK if it is
     fn ensure_pending(&self, token_id: &Id, asset_id: &AssetId) -> Result<()>;
 
+    /// Check that the account can manage the token's assets.
+    /// Return error if it can't
+    fn ensure_can_manage_assets(&self, account: AccountId, token_id: &Id) -> Result<()>;
+
     /// Check if asset is already accepted
     fn ensure_asset_accepted(&self, token_id: &Id, asset_id: &AssetId) -> Result<()>;
 
@@ -57,7 +67,10 @@ pub trait Internal {
 /// Implement internal helper trait for MultiAsset
 impl<T> Internal for T
 where
-    T: Storage<MultiAssetData> + Storage<psp34::Data<enumerable::Balances>> + Utils,
+    T: Storage<MultiAssetData>
+        + Storage<psp34::Data<enumerable::Balances>>
+        + OwnershipHooks
+        + Utils,
 {
     /// Check if asset is already accepted
     default fn ensure_not_accepted(&self, token_id: &Id, asset_id: &AssetId) -> Result<()> {
@@ -89,6 +102,14 @@ where
         Ok(())
     }
 
+    /// Check that the account is approved for the token's assets, or can manage the token
+    default fn ensure_can_manage_assets(&self, account: AccountId, token_id: &Id) -> Result<()> {
+        if self.data::<MultiAssetData>().asset_approvals.get(token_id) == Some(account) {
+            return Ok(())
+        }
+        self._ensure_root_owner(account, token_id)
+    }
+
     /// Check if asset is already accepted
     default fn ensure_asset_accepted(&self, token_id: &Id, asset_id: &AssetId) -> Result<()> {
         if let Some(assets) = self.data::<MultiAssetData>().accepted_assets.get(token_id) {
@@ -19,7 +19,10 @@ use rmrk_common::{
     },
     roles::CONTRIBUTOR,
     types::*,
-    utils::Utils,
+    utils::{
+        OwnershipHooks,
+        Utils,
+    },
 };
 
 use traits::{
@@ -65,6 +68,10 @@ pub struct MultiAssetData {
     /// Catalog assigned to assetId. Added with add_asset_entry
     /// An asset can also have None as a catalog, hence the Option
     pub asset_catalog_address: Mapping<AssetId, Option<AccountId>>,
+
+    /// Mapping of tokenId to the account approved to manage its assets.
+    /// Reset when the token is transferred
+    pub asset_approvals: Mapping<Id, AccountId>,
 }
 
 impl<T> MultiAsset for T
@@ -72,6 +79,7 @@ where
     T: Storage<MultiAssetData>
         + Storage<psp34::Data<enumerable::Balances>>
         + Storage<access_control::Data>
+        + OwnershipHooks
         + Utils,
 {
     /// Used to add a asset entry.
@@ -145,22 +153,16 @@ where
     /// Accepts an asset from the pending array of given token.
     fn accept_asset(&mut self, token_id: Id, asset_id: AssetId) -> Result<()> {
         self.ensure_pending(&token_id, &asset_id)?;
-        let token_owner = self.ensure_exists_and_get_owner(&token_id)?;
-        let caller = Self::env().caller();
-        if caller == token_owner {
-            self.remove_from_pending_assets(&token_id, &asset_id)?;
-            self.add_to_accepted_assets(&token_id, &asset_id);
-        } else {
-            return Err(RmrkError::NotTokenOwner.into())
-        }
+        self.ensure_can_manage_assets(Self::env().caller(), &token_id)?;
+        self.remove_from_pending_assets(&token_id, &asset_id)?;
+        self.add_to_accepted_assets(&token_id, &asset_id);
         Ok(())
     }
 
     /// Rejects an asset from the pending array of given token.
     fn reject_asset(&mut self, token_id: Id, asset_id: AssetId) -> Result<()> {
         self.ensure_pending(&token_id, &asset_id)?;
-        let token_owner = self.ensure_exists_and_get_owner(&token_id)?;
-        self.ensure_token_owner(token_owner)?;
+        self.ensure_can_manage_assets(Self::env().caller(), &token_id)?;
 
         self.remove_from_pending_assets(&token_id, &asset_id)?;
 
@@ -182,8 +184,7 @@ where
 
     /// Used to specify the priorities for a given token's active assets.
     fn set_priority(&mut self, token_id: Id, priorities: Vec<AssetId>) -> Result<()> {
-        let token_owner = self.ensure_exists_and_get_owner(&token_id)?;
-        self.ensure_token_owner(token_owner)?;
+        self.ensure_can_manage_assets(Self::env().caller(), &token_id)?;
         if let Some(accepted_assets) = self
             .data::<MultiAssetData>()
             .accepted_assets
@@ -206,6 +207,44 @@ where
         Ok(())
     }
 
+    /// Approve an account to manage the assets of the token, or revoke the approval.
+    fn approve_for_assets(&mut self, operator: Option<AccountId>, token_id: Id) -> Result<()> {
+        self._ensure_root_owner(Self::env().caller(), &token_id)?;
+        match operator {
+            Some(operator) => {
+                self.data::<MultiAssetData>()
+                    .asset_approvals
+                    .insert(&token_id, &operator);
+            }
+            None => {
+                self.data::<MultiAssetData>()
+                    .asset_approvals
+                    .remove(&token_id);
+            }
+        }
+        self._emit_approval_for_assets_event(&token_id, &operator);
+        Ok(())
+    }
+
+    /// Fetch the account approved to manage the assets of the token
+    fn get_approved_for_assets(&self, token_id: Id) -> Option<AccountId> {
+        self.data::<MultiAssetData>().asset_approvals.get(&token_id)
+    }
+
+    /// Reset the asset approval of the transferred token
+    default fn reset_approval_for_assets(&mut self, token_id: &Id) {
+        if self
+            .data::<MultiAssetData>()
+            .asset_approvals
+            .contains(token_id)
+        {
+            self.data::<MultiAssetData>()
+                .asset_approvals
+                .remove(token_id);
+            self._emit_approval_for_assets_event(token_id, &None);
+        }
+    }
+
     /// Used to retrieve the total number of asset entries
     fn total_assets(&self) -> u32 {
         self.data::<MultiAssetData>().collection_asset_ids.len() as u32
@@ -313,4 +352,12 @@ where
 
     /// Used to notify listeners that token's prioritiy array is reordered.
     default fn _emit_asset_priority_set_event(&self, _token_id: &Id, _priorities: Vec<AssetId>) {}
+
+    /// Used to notify listeners that the account approved to manage the token's assets changed.
+    default fn _emit_approval_for_assets_event(
+        &self,
+        _token_id: &Id,
+        _operator: &Option<AccountId>,
+    ) {
+    }
 }
@@ -62,7 +62,8 @@ pub trait MultiAsset {
     /// Migrates the asset from the token's pending asset array to the token's active asset array.
     /// Active assets cannot be removed by anyone, but can be replaced by a new asset.
     /// # Requirements:
-    ///  * The caller must own the token or be approved to manage the token's assets
+    ///  * The caller must own the token, be its root owner if `_ensure_root_owner` allows it,
+    ///    or be approved to manage the token's assets
     ///  * `tokenId` must exist.
     ///  * `assetId` must be in the pending_asset list.
     /// # Arguments
@@ -75,7 +76,8 @@ pub trait MultiAsset {
     /// Rejects an asset from the pending array of given token.
     /// Removes the asset from the token's pending asset array.
     /// # Requirements:
-    ///  * The caller must own the token or be approved to manage the token's assets
+    ///  * The caller must own the token, be its root owner if `_ensure_root_owner` allows it,
+    ///    or be approved to manage the token's assets
     ///  * `tokenId` must exist.
     ///  * `assetId` must be in the pending_asset list.
     /// # Arguments
@@ -86,6 +88,7 @@ pub trait MultiAsset {
     fn reject_asset(&mut self, token_id: Id, asset_id: AssetId) -> Result<()>;
 
     /// Used to specify the priorities for a given token's active assets.
+    /// The caller must own the token, or be approved to manage the token's assets.
     /// If the length of the priorities array doesn't match the length of the active assets array, the execution
     ///  will be reverted.
     /// The position of the priority value in the array corresponds the position of the asset in the active
@@ -97,6 +100,26 @@ pub trait MultiAsset {
     #[ink(message)]
     fn set_priority(&mut self, token_id: Id, priorities: Vec<AssetId>) -> Result<()>;
 
+    /// Approve an account to accept, reject and prioritize the assets of the token.
+    /// The approval is reset when the token is transferred.
+    /// # Requirements:
+    ///  * The caller must own the token, or be its root owner if `_ensure_root_owner` allows it
+    /// # Arguments
+    ///  * operator Account to approve, or None to revoke the approval
+    ///  * tokenId ID of the token
+    /// Emits an {ApprovalForAssets} event.
+    #[ink(message)]
+    fn approve_for_assets(&mut self, operator: Option<AccountId>, token_id: Id) -> Result<()>;
+
+    /// Used to retrieve the account approved to manage the assets of the token
+    #[ink(message)]
+    fn get_approved_for_assets(&self, token_id: Id) -> Option<AccountId>;
+
+    /// Reset the asset approval of the token transferred to a new owner.
+    /// To be called from `psp34::Internal::_before_token_transfer`.
+    /// Emits an {ApprovalForAssets} event if the token had an approval.
+    fn reset_approval_for_assets(&mut self, token_id: &Id);
+
     /// Used to retrieve the total number of assets.
     /// # Returns
     ///  * u64 The total number of assets
@@ -181,6 +204,12 @@ pub trait MultiAssetEvents {
     /// # Arguments
     /// * tokenId ID of the token that had the asset priority array updated
     fn _emit_asset_priority_set_event(&self, token_id: &Id, priorities: Vec<AssetId>);
+
+    /// Used to notify listeners that the account approved to manage the token's assets changed.
+    /// # Arguments
+    /// * tokenId ID of the token
+    /// * operator Account approved to manage the assets, None if the approval was reset
+    fn _emit_approval_for_assets_event(&self, token_id: &Id, operator: &Option<AccountId>);
 }
 
 /// Trait definitions for MultiAssetAutoIndex functions
@@ -1,6 +1,16 @@
 use crate::{
-    traits::NestingEvents,
+    traits::{
+        NestingEvents,
+        NestingHooks,
+        NestingHooksRef,
+        NestingRef,
+        Ownership,
+        OwnershipRef,
+    },
+    ChildOrigin,
+    ChildStatus,
     NestingData,
+    MAX_NESTING_DEPTH,
 };
 
 use rmrk_common::{
@@ -8,6 +18,7 @@ use rmrk_common::{
         Result,
         RmrkError,
     },
+    soulbound::SoulboundRef,
     types::*,
 };
 
@@ -37,18 +48,133 @@ pub trait Internal {
     /// Remove the child to the list of accepted children.
     fn remove_accepted(&mut self, parent_token_id: &Id, child_nft: &ChildNft) -> Result<()>;
 
-    /// Add the child to the list of pending children.
-    fn add_to_pending(&mut self, parent_token_id: Id, child_nft: ChildNft);
+    /// Add the child to the list of pending children, recording its sender and the time.
+    fn add_to_pending(&mut self, parent_token_id: Id, child_nft: ChildNft, sender: AccountId);
+
+    /// Remove the child to the list of pending children, returning its origin if recorded.
+    fn remove_from_pending(
+        &mut self,
+        parent_token_id: &Id,
+        child_nft: &ChildNft,
+    ) -> Result<Option<ChildOrigin>>;
+
+    /// Children with the status in the legacy layout, until the parent token is migrated.
+    fn legacy_children(&self, parent_token_id: &Id, status: ChildStatus) -> Option<Vec<ChildNft>>;
+
+    /// Number of children with the status in the parent token.
+    fn children_count(&self, parent_token_id: &Id, status: ChildStatus) -> u64;
+
+    /// Children with the status in the parent token, from `offset` up to `limit` children.
+    fn children_page(
+        &self,
+        parent_token_id: &Id,
+        status: ChildStatus,
+        offset: u64,
+        limit: u64,
+    ) -> Vec<ChildNft>;
+
+    /// Append the child to the children with the status. Returns false if already there.
+    fn add_child_entry(
+        &mut self,
+        parent_token_id: &Id,
+        status: ChildStatus,
+        child_nft: &ChildNft,
+    ) -> bool;
+
+    /// Remove the child from the children with the status.
+    fn remove_child_entry(
+        &mut self,
+        parent_token_id: &Id,
+        status: ChildStatus,
+        child_nft: &ChildNft,
+    ) -> Result<()>;
+
+    /// Remove and return all children with the status from the parent token.
+    fn take_children(&mut self, parent_token_id: &Id, status: ChildStatus) -> Vec<ChildNft>;
 
-    /// Remove the child to the list of pending children.
-    fn remove_from_pending(&mut self, parent_token_id: &Id, child_nft: &ChildNft) -> Result<()>;
+    /// Move children of the parent token from the legacy layout.
+    /// Returns the number of children moved.
+    fn migrate_legacy_children(&mut self, parent_token_id: &Id) -> u64;
 
-    /// Check if caller is the owner of this parent token.
+    /// Check if caller is the owner, or the root owner, of this parent token.
     fn is_caller_parent_owner(&self, caller: AccountId, parent_token_id: &Id) -> Result<()>;
 
+    /// Check that the child collection is approved and under its limit for the parent token.
+    fn ensure_child_collection_approved(
+        &self,
+        parent_token_id: &Id,
+        collection: &AccountId,
+    ) -> Result<()>;
+
     /// Cross contract call to transfer child nft ownership.
     fn transfer_child_ownership(&self, to: AccountId, child_nft: ChildNft) -> Result<()>;
 
+    /// Transfer child nft ownership, or let the account claim it later if the transfer fails.
+    fn return_child(&mut self, to: AccountId, child_nft: ChildNft);
+
+    /// Cross contract call to get the owner of child nft.
+    fn child_owner(&self, child_nft: &ChildNft) -> Result<AccountId>;
+
+    /// Cross contract call to get the root owner of child nft.
+    fn child_root_owner(&self, child_nft: &ChildNft) -> Result<AccountId>;
+
+    /// Check that the operator owns the child nft or is approved to transfer it.
+    fn ensure_child_operator(
+        &self,
+        operator: AccountId,
+        child_owner: AccountId,
+        child_nft: &ChildNft,
+    ) -> Result<()>;
+
+    /// Cross contract call to approve the operator to transfer child nft.
+    fn approve_child_transfer(&self, operator: AccountId, child_nft: &ChildNft) -> Result<()>;
+
+    /// Call the nesting hooks of the child and this collection before adding the child.
+    fn call_nesting_hooks(&mut self, parent_token_id: &Id, child_nft: &ChildNft) -> Result<()>;
+
+    /// Add child nft owned by this contract to the parent token.
+    fn register_child(
+        &mut self,
+        parent_token_id: Id,
+        child_nft: ChildNft,
+        sender: AccountId,
+    ) -> Result<()>;
+
+    /// Add child nft owned by this contract to the parent token as pending.
+    /// `origin` gets the child back if it is rejected.
+    fn register_pending_child(
+        &mut self,
+        parent_token_id: Id,
+        child_nft: ChildNft,
+        origin: AccountId,
+    ) -> Result<()>;
+
+    /// Cross contract call to check if child nft is soulbound.
+    fn is_child_locked(&self, child_nft: &ChildNft) -> bool;
+
+    /// Check children count and nesting depth limits for a new child of the parent token,
+    /// and that the child is not an ancestor of the parent token.
+    fn ensure_nesting_limits(
+        &self,
+        parent_token_id: &Id,
+        child_nft: &ChildNft,
+        accepted: bool,
+    ) -> Result<()>;
+
+    /// Parents of the token, across collections, from the closest one up to `limit` levels.
+    fn ancestors(&self, token_id: &Id, limit: u32) -> Vec<ChildNft>;
+
+    /// Levels of accepted children below the child nft, across collections, up to `limit` levels.
+    fn children_depth_of(&self, child_nft: &ChildNft, limit: u32) -> u32;
+
+    /// Check that the child is neither the parent token nor one of its `ancestors`.
+    fn ensure_no_cycle(
+        &self,
+        parent_token_id: &Id,
+        child_nft: &ChildNft,
+        ancestors: &[ChildNft],
+    ) -> Result<()>;
+
     /// Set the owner of the provided child nft.
     fn set_parent(&mut self, child_nft: &ChildNft, parent_token_id: Id);
 
@@ -63,44 +189,37 @@ where
 {
     /// Check if child is already accepted
     default fn accepted(&self, parent_token_id: &Id, child_nft: &ChildNft) -> Result<()> {
-        if let Some(children) = self
-            .data::<NestingData>()
-            .accepted_children
-            .get(parent_token_id)
+        if self.data::<NestingData>().child_index.contains((
+            parent_token_id,
+            ChildStatus::Accepted,
+            child_nft,
+        )) || self
+            .legacy_children(parent_token_id, ChildStatus::Accepted)
+            .map_or(false, |children| children.contains(child_nft))
         {
-            if children.contains(child_nft) {
-                return Err(RmrkError::AlreadyAddedChild.into())
-            }
+            return Err(RmrkError::AlreadyAddedChild.into())
         }
         Ok(())
     }
 
     /// Check if child is already pending
     default fn pending(&self, parent_token_id: &Id, child_nft: &ChildNft) -> Result<()> {
-        if let Some(children) = self
-            .data::<NestingData>()
-            .pending_children
-            .get(parent_token_id)
+        if self.data::<NestingData>().child_index.contains((
+            parent_token_id,
+            ChildStatus::Pending,
+            child_nft,
+        )) || self
+            .legacy_children(parent_token_id, ChildStatus::Pending)
+            .map_or(false, |children| children.contains(child_nft))
         {
-            if children.contains(child_nft) {
-                return Err(RmrkError::AddingPendingChild.into())
-            }
+            return Err(RmrkError::AddingPendingChild.into())
         }
         Ok(())
     }
 
     /// Add the child to the list of accepted children
     default fn add_to_accepted(&mut self, parent_token_id: Id, child_nft: ChildNft) {
-        let mut child_nfts = self
-            .data::<NestingData>()
-            .accepted_children
-            .get(&parent_token_id)
-            .unwrap_or(Vec::new());
-        if !child_nfts.contains(&child_nft) {
-            child_nfts.push(child_nft.clone());
-            self.data::<NestingData>()
-                .accepted_children
-                .insert(&parent_token_id, &child_nfts);
+        if self.add_child_entry(&parent_token_id, ChildStatus::Accepted, &child_nft) {
             self._emit_child_accepted_event(&parent_token_id, &child_nft.0, &child_nft.1);
         }
     }
@@ -111,66 +230,220 @@ where
         parent_token_id: &Id,
         child_nft: &ChildNft,
     ) -> Result<()> {
-        let mut child_nfts = self
-            .data::<NestingData>()
-            .accepted_children
-            .get(parent_token_id)
-            .ok_or(RmrkError::InvalidParentId)?;
-
-        let index = child_nfts
-            .iter()
-            .position(|x| x == child_nft)
-            .ok_or(RmrkError::ChildNotFound)?;
-        child_nfts.remove(index);
-
-        self.data::<NestingData>()
-            .accepted_children
-            .insert(parent_token_id, &child_nfts);
-
+        self.remove_child_entry(parent_token_id, ChildStatus::Accepted, child_nft)?;
         self._emit_child_removed_event(parent_token_id, &child_nft.0, &child_nft.1);
         Ok(())
     }
 
-    /// Add the child to the list of pending children
-    default fn add_to_pending(&mut self, parent_token_id: Id, child_nft: ChildNft) {
-        let mut child_nfts = self
-            .data::<NestingData>()
-            .pending_children
-            .get(&parent_token_id)
-            .unwrap_or(Vec::new());
-        if !child_nfts.contains(&child_nft) {
-            child_nfts.push(child_nft);
+    /// Add the child to the list of pending children, recording its sender
+    default fn add_to_pending(
+        &mut self,
+        parent_token_id: Id,
+        child_nft: ChildNft,
+        sender: AccountId,
+    ) {
+        if self.add_child_entry(&parent_token_id, ChildStatus::Pending, &child_nft) {
+            let origin = ChildOrigin {
+                sender,
+                timestamp: Self::env().block_timestamp(),
+            };
             self.data::<NestingData>()
-                .pending_children
-                .insert(&parent_token_id, &child_nfts);
+                .pending_child_origin
+                .insert(&child_nft, &origin);
         }
     }
 
-    /// Remove the child to the list of pending children
+    /// Remove the child to the list of pending children, returning its origin
     default fn remove_from_pending(
         &mut self,
         parent_token_id: &Id,
         child_nft: &ChildNft,
-    ) -> Result<()> {
-        let mut child_nfts = self
+    ) -> Result<Option<ChildOrigin>> {
+        self.remove_child_entry(parent_token_id, ChildStatus::Pending, child_nft)?;
+        Ok(self
             .data::<NestingData>()
-            .pending_children
-            .get(parent_token_id)
-            .ok_or(RmrkError::InvalidParentId)?;
+            .pending_child_origin
+            .take(child_nft))
+    }
 
-        let index = child_nfts
-            .iter()
-            .position(|x| x == child_nft)
-            .ok_or(RmrkError::ChildNotFound)?;
-        child_nfts.remove(index);
+    /// Children with the status in the legacy layout, until the parent token is migrated
+    default fn legacy_children(
+        &self,
+        parent_token_id: &Id,
+        status: ChildStatus,
+    ) -> Option<Vec<ChildNft>> {
+        let nesting = self.data::<NestingData>();
+        match status {
+            ChildStatus::Accepted => nesting.accepted_children.get(parent_token_id),
+            ChildStatus::Pending => nesting.pending_children.get(parent_token_id),
+        }
+    }
 
+    /// Number of children with the status in the parent token.
+    /// Children of a parent token not migrated yet are counted from the legacy layout.
+    default fn children_count(&self, parent_token_id: &Id, status: ChildStatus) -> u64 {
+        let legacy_count = self
+            .legacy_children(parent_token_id, status)
+            .map_or(0, |children| children.len() as u64);
         self.data::<NestingData>()
-            .pending_children
-            .insert(parent_token_id, &child_nfts);
+            .children_count
+            .get((parent_token_id, status))
+            .unwrap_or_default()
+            .saturating_add(legacy_count)
+    }
+
+    /// Children with the status in the parent token, from `offset` up to `limit` children
+    default fn children_page(
+        &self,
+        parent_token_id: &Id,
+        status: ChildStatus,
+        offset: u64,
+        limit: u64,
+    ) -> Vec<ChildNft> {
+        if let Some(children) = self.legacy_children(parent_token_id, status) {
+            return children
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect()
+        }
+        let end = offset
+            .saturating_add(limit)
+            .min(self.children_count(parent_token_id, status));
+        (offset..end)
+            .filter_map(|index| {
+                self.data::<NestingData>()
+                    .children
+                    .get((parent_token_id, status, index))
+            })
+            .collect()
+    }
+
+    /// Append the child at the end of the children with the status.
+    /// Returns false if the child is already there.
+    default fn add_child_entry(
+        &mut self,
+        parent_token_id: &Id,
+        status: ChildStatus,
+        child_nft: &ChildNft,
+    ) -> bool {
+        self.migrate_legacy_children(parent_token_id);
+        push_child_entry(
+            self.data::<NestingData>(),
+            parent_token_id,
+            status,
+            child_nft,
+        )
+    }
+
+    /// Remove the child from the children with the status.
+    /// The last child takes the place of the removed one.
+    default fn remove_child_entry(
+        &mut self,
+        parent_token_id: &Id,
+        status: ChildStatus,
+        child_nft: &ChildNft,
+    ) -> Result<()> {
+        self.migrate_legacy_children(parent_token_id);
+        let nesting = self.data::<NestingData>();
+        let count = nesting
+            .children_count
+            .get((parent_token_id, status))
+            .unwrap_or_default();
+        if count == 0 {
+            return Err(RmrkError::InvalidParentId.into())
+        }
+        let index = nesting
+            .child_index
+            .take((parent_token_id, status, child_nft))
+            .ok_or(RmrkError::ChildNotFound)?;
 
+        let last = count - 1;
+        if index != last {
+            if let Some(last_child) = nesting.children.get((parent_token_id, status, last)) {
+                nesting
+                    .children
+                    .insert((parent_token_id, status, index), &last_child);
+                nesting
+                    .child_index
+                    .insert((parent_token_id, status, &last_child), &index);
+            }
+        }
+        nesting.children.remove((parent_token_id, status, last));
+        nesting
+            .children_count
+            .insert((parent_token_id, status), &last);
+
+        let collection_count = nesting
+            .collection_children_count
+            .get((parent_token_id, &child_nft.0))
+            .unwrap_or_default();
+        nesting.collection_children_count.insert(
+            (parent_token_id, &child_nft.0),
+            &collection_count.saturating_sub(1),
+        );
         Ok(())
     }
 
+    /// Remove and return all children with the status from the parent token.
+    /// Costs storage access for every child, limit the children count if it matters.
+    default fn take_children(
+        &mut self,
+        parent_token_id: &Id,
+        status: ChildStatus,
+    ) -> Vec<ChildNft> {
+        self.migrate_legacy_children(parent_token_id);
+        let count = self.children_count(parent_token_id, status);
+        let nesting = self.data::<NestingData>();
+        let mut children = Vec::new();
+        for index in 0..count {
+            if let Some(child_nft) = nesting.children.take((parent_token_id, status, index)) {
+                nesting
+                    .child_index
+                    .remove((parent_token_id, status, &child_nft));
+                let collection_count = nesting
+                    .collection_children_count
+                    .get((parent_token_id, &child_nft.0))
+                    .unwrap_or_default();
+                nesting.collection_children_count.insert(
+                    (parent_token_id, &child_nft.0),
+                    &collection_count.saturating_sub(1),
+                );
+                children.push(child_nft);
+            }
+        }
+        nesting.children_count.remove((parent_token_id, status));
+        children
+    }
+
+    /// Move children of the parent token from the legacy vector layout to the indexed one
+    default fn migrate_legacy_children(&mut self, parent_token_id: &Id) -> u64 {
+        let accepted_children = self
+            .data::<NestingData>()
+            .accepted_children
+            .take(parent_token_id)
+            .unwrap_or_default();
+        let pending_children = self
+            .data::<NestingData>()
+            .pending_children
+            .take(parent_token_id)
+            .unwrap_or_default();
+
+        let nesting = self.data::<NestingData>();
+        let mut migrated = 0;
+        for child_nft in accepted_children {
+            if push_child_entry(nesting, parent_token_id, ChildStatus::Accepted, &child_nft) {
+                migrated += 1;
+            }
+        }
+        for child_nft in pending_children {
+            if push_child_entry(nesting, parent_token_id, ChildStatus::Pending, &child_nft) {
+                migrated += 1;
+            }
+        }
+        migrated
+    }
+
     /// Set the owner of the child nft.
     default fn set_parent(&mut self, child_nft: &ChildNft, parent_token_id: Id) {
         self.data::<NestingData>()
@@ -183,34 +456,421 @@ where
         self.data::<NestingData>().parent_of.take(child_nft);
     }
 
-    /// Check if caller is the owner of this parent token
+    /// Check if caller is the owner, or the root owner, of this parent token
     default fn is_caller_parent_owner(
         &self,
         caller: AccountId,
         parent_token_id: &Id,
     ) -> Result<()> {
-        if let Some(token_owner) = self
+        if self
             .data::<psp34::Data<enumerable::Balances>>()
             .owner_of(parent_token_id.clone())
+            .is_some()
         {
-            if token_owner != caller {
-                return Err(RmrkError::NotTokenOwner.into())
-            }
+            self.ensure_root_owner(caller, parent_token_id)?;
+        }
+        Ok(())
+    }
+
+    /// Check that the child collection is approved and under its limit for the parent token.
+    /// Children from this collection are always allowed.
+    default fn ensure_child_collection_approved(
+        &self,
+        parent_token_id: &Id,
+        collection: &AccountId,
+    ) -> Result<()> {
+        if *collection == Self::env().account_id() {
+            return Ok(())
+        }
+        let nesting = self.data::<NestingData>();
+        let max_children = match nesting.approved_child_collections.get(collection) {
+            Some(Some(max_children)) => max_children,
+            Some(None) => return Ok(()),
+            None if !nesting.restrict_child_collections => return Ok(()),
+            None => return Err(RmrkError::ChildContractNotApproved.into()),
+        };
+
+        let legacy_count = [ChildStatus::Accepted, ChildStatus::Pending]
+            .into_iter()
+            .filter_map(|status| self.legacy_children(parent_token_id, status))
+            .flatten()
+            .filter(|child_nft| child_nft.0 == *collection)
+            .count() as u64;
+        let children_count = nesting
+            .collection_children_count
+            .get((parent_token_id, collection))
+            .unwrap_or_default()
+            .saturating_add(legacy_count);
+        if children_count >= max_children {
+            return Err(RmrkError::MaxCollectionChildrenReached.into())
         }
         Ok(())
     }
 
-    /// Cross contract call to transfer child nft ownership
+    /// Cross contract call to transfer child nft ownership.
+    /// A failed transfer is reported with its cause instead of trapping.
     default fn transfer_child_ownership(&self, to: AccountId, child_nft: ChildNft) -> Result<()> {
-        // TODO check child collection is approved by this (parent) collection
-        // let collection = self.get_collection(child_nft.0)
-        //      .ok_or(RmrkError::ChildContractNotApproved)?;
+        match PSP34Ref::transfer_builder(&child_nft.0, to, child_nft.1, Vec::new())
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+        {
+            Ok(Ok(Ok(()))) => Ok(()),
+            Ok(Ok(Err(err))) => Err(RmrkError::ChildTransferFailed(err.into()).into()),
+            Ok(Err(err)) => Err(RmrkError::ChildTransferFailed(err.into()).into()),
+            Err(err) => Err(RmrkError::ChildTransferFailed(err.into()).into()),
+        }
+    }
+
+    /// Transfer child nft ownership, so that a child which can't be transferred
+    /// doesn't block the caller. The child stays with this contract until claimed.
+    default fn return_child(&mut self, to: AccountId, child_nft: ChildNft) {
+        if self
+            .transfer_child_ownership(to, child_nft.clone())
+            .is_err()
+        {
+            self.data::<NestingData>()
+                .claimable_children
+                .insert(&child_nft, &to);
+        }
+    }
+
+    /// Cross contract call to approve the operator to transfer child nft.
+    default fn approve_child_transfer(
+        &self,
+        operator: AccountId,
+        child_nft: &ChildNft,
+    ) -> Result<()> {
+        match PSP34Ref::approve_builder(&child_nft.0, operator, Some(child_nft.1.clone()), true)
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+        {
+            Ok(Ok(Ok(()))) => Ok(()),
+            Ok(Ok(Err(err))) => Err(RmrkError::ChildTransferFailed(err.into()).into()),
+            Ok(Err(err)) => Err(RmrkError::ChildTransferFailed(err.into()).into()),
+            Err(err) => Err(RmrkError::ChildTransferFailed(err.into()).into()),
+        }
+    }
+
+    /// Cross contract call to get the owner of child nft.
+    /// Children from this collection are read from local storage.
+    default fn child_owner(&self, child_nft: &ChildNft) -> Result<AccountId> {
+        let owner = if child_nft.0 == Self::env().account_id() {
+            self.data::<psp34::Data<enumerable::Balances>>()
+                .owner_of(child_nft.1.clone())
+        } else {
+            match PSP34Ref::owner_of_builder(&child_nft.0, child_nft.1.clone())
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke()
+            {
+                Ok(Ok(owner)) => owner,
+                Ok(Err(err)) => return Err(RmrkError::ChildQueryFailed(err.into()).into()),
+                Err(err) => return Err(RmrkError::ChildQueryFailed(err.into()).into()),
+            }
+        };
+        Ok(owner.ok_or(PSP34Error::TokenNotExists)?)
+    }
+
+    /// Cross contract call to get the root owner of child nft.
+    /// Children from this collection are resolved locally.
+    default fn child_root_owner(&self, child_nft: &ChildNft) -> Result<AccountId> {
+        if child_nft.0 == Self::env().account_id() {
+            return self.root_owner(child_nft.1.clone())
+        }
+        match OwnershipRef::root_owner_builder(&child_nft.0, child_nft.1.clone())
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+        {
+            Ok(Ok(result)) => result,
+            Ok(Err(err)) => Err(RmrkError::ChildQueryFailed(err.into()).into()),
+            Err(err) => Err(RmrkError::ChildQueryFailed(err.into()).into()),
+        }
+    }
+
+    /// Check that the operator owns the child nft or is approved to transfer it.
+    /// Approvals are read from the child collection (cross contract call).
+    default fn ensure_child_operator(
+        &self,
+        operator: AccountId,
+        child_owner: AccountId,
+        child_nft: &ChildNft,
+    ) -> Result<()> {
+        if operator == child_owner {
+            return Ok(())
+        }
+        match PSP34Ref::allowance_builder(
+            &child_nft.0,
+            child_owner,
+            operator,
+            Some(child_nft.1.clone()),
+        )
+        .call_flags(CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        {
+            Ok(Ok(true)) => Ok(()),
+            Ok(Ok(false)) => Err(PSP34Error::NotApproved.into()),
+            Ok(Err(err)) => Err(RmrkError::ChildQueryFailed(err.into()).into()),
+            Err(err) => Err(RmrkError::ChildQueryFailed(err.into()).into()),
+        }
+    }
 
-        PSP34Ref::transfer_builder(&child_nft.0, to, child_nft.1, Vec::new())
+    /// Call the nesting hooks of the child and this collection before adding the child.
+    /// Only child collections without `on_nested` message consent to nesting,
+    /// any other failure of the call vetoes the child.
+    default fn call_nesting_hooks(
+        &mut self,
+        parent_token_id: &Id,
+        child_nft: &ChildNft,
+    ) -> Result<()> {
+        let this_collection = Self::env().account_id();
+        if child_nft.0 == this_collection {
+            self._on_nested(this_collection, parent_token_id, &child_nft.1)?;
+        } else {
+            match NestingHooksRef::on_nested_builder(
+                &child_nft.0,
+                parent_token_id.clone(),
+                child_nft.1.clone(),
+            )
             .call_flags(CallFlags::default().set_allow_reentry(true))
-            .invoke()
-            .unwrap();
+            .try_invoke()
+            {
+                Ok(Ok(result)) => result?,
+                Ok(Err(ink::LangError::CouldNotReadInput)) => (),
+                Ok(Err(err)) => return Err(RmrkError::ChildHookFailed(err.into()).into()),
+                Err(err) => return Err(RmrkError::ChildHookFailed(err.into()).into()),
+            }
+        }
+        self.on_child_received(parent_token_id, child_nft)
+    }
 
+    /// Cross contract call to check if child nft is soulbound.
+    /// Child collections without soulbound support are never locked.
+    default fn is_child_locked(&self, child_nft: &ChildNft) -> bool {
+        matches!(
+            SoulboundRef::locked_builder(&child_nft.0, child_nft.1.clone())
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke(),
+            Ok(Ok(Ok(true)))
+        )
+    }
+
+    /// Add child nft owned by this contract to the parent token.
+    /// The child is accepted if the sender owns the parent token, pending otherwise.
+    default fn register_child(
+        &mut self,
+        parent_token_id: Id,
+        child_nft: ChildNft,
+        sender: AccountId,
+    ) -> Result<()> {
+        let parent_owner = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .owner_of(parent_token_id.clone())
+            .ok_or(PSP34Error::TokenNotExists)?;
+        if sender != parent_owner {
+            return self.register_pending_child(parent_token_id, child_nft, sender)
+        }
+        self.accepted(&parent_token_id, &child_nft)?;
+        self.pending(&parent_token_id, &child_nft)?;
+        self.ensure_child_collection_approved(&parent_token_id, &child_nft.0)?;
+        self.ensure_nesting_limits(&parent_token_id, &child_nft, true)?;
+        self.call_nesting_hooks(&parent_token_id, &child_nft)?;
+
+        self._emit_added_child_event(&parent_token_id, &child_nft.0, &child_nft.1);
+        self.set_parent(&child_nft, parent_token_id.clone());
+        self.add_to_accepted(parent_token_id, child_nft);
+        Ok(())
+    }
+
+    /// Add child nft owned by this contract to the parent token as pending.
+    default fn register_pending_child(
+        &mut self,
+        parent_token_id: Id,
+        child_nft: ChildNft,
+        origin: AccountId,
+    ) -> Result<()> {
+        self.data::<psp34::Data<enumerable::Balances>>()
+            .owner_of(parent_token_id.clone())
+            .ok_or(PSP34Error::TokenNotExists)?;
+        self.accepted(&parent_token_id, &child_nft)?;
+        self.pending(&parent_token_id, &child_nft)?;
+        self.ensure_child_collection_approved(&parent_token_id, &child_nft.0)?;
+        self.ensure_nesting_limits(&parent_token_id, &child_nft, false)?;
+        self.call_nesting_hooks(&parent_token_id, &child_nft)?;
+
+        self._emit_added_child_event(&parent_token_id, &child_nft.0, &child_nft.1);
+        self.add_to_pending(parent_token_id, child_nft, origin);
+        Ok(())
+    }
+
+    /// Check children count and nesting depth limits for a new child of the parent token,
+    /// and that the child is not an ancestor of the parent token
+    default fn ensure_nesting_limits(
+        &self,
+        parent_token_id: &Id,
+        child_nft: &ChildNft,
+        accepted: bool,
+    ) -> Result<()> {
+        let nesting = self.data::<NestingData>();
+        if accepted {
+            if let Some(max_accepted) = nesting.max_accepted_children {
+                if self.children_count(parent_token_id, ChildStatus::Accepted) >= max_accepted {
+                    return Err(RmrkError::MaxAcceptedChildrenReached.into())
+                }
+            }
+        } else if let Some(max_pending) = nesting.max_pending_children {
+            if self.children_count(parent_token_id, ChildStatus::Pending) >= max_pending {
+                return Err(RmrkError::MaxPendingChildrenReached.into())
+            }
+        }
+
+        // The child is nested one level below the parent token, with its own children below it
+        let limit = nesting.max_depth.unwrap_or(MAX_NESTING_DEPTH);
+        let ancestors = self.ancestors(parent_token_id, limit);
+        self.ensure_no_cycle(parent_token_id, child_nft, &ancestors)?;
+        if ancestors.len() as u32 >= limit {
+            return Err(RmrkError::MaxNestingDepthReached.into())
+        }
+        let levels_left = limit - ancestors.len() as u32 - 1;
+        if self.children_depth_of(child_nft, levels_left + 1) > levels_left {
+            return Err(RmrkError::MaxNestingDepthReached.into())
+        }
         Ok(())
     }
+
+    /// Check that the child is neither the parent token nor one of its ancestors
+    default fn ensure_no_cycle(
+        &self,
+        parent_token_id: &Id,
+        child_nft: &ChildNft,
+        ancestors: &[ChildNft],
+    ) -> Result<()> {
+        if *child_nft == (Self::env().account_id(), parent_token_id.clone())
+            || ancestors.contains(child_nft)
+        {
+            return Err(RmrkError::NestingCycle.into())
+        }
+        Ok(())
+    }
+
+    /// Walk up the parents of the token, across collections, up to `limit` levels.
+    /// The walk stops at the first owner which is not a parent token.
+    /// Owners which are not contracts hold no parent tokens.
+    default fn ancestors(&self, token_id: &Id, limit: u32) -> Vec<ChildNft> {
+        let this_collection = Self::env().account_id();
+        let mut child_nft: ChildNft = (this_collection, token_id.clone());
+        let mut owner = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .owner_of(token_id.clone());
+        let mut ancestors = Vec::new();
+
+        while let Some(parent_collection) = owner {
+            if ancestors.len() as u32 >= limit {
+                break
+            }
+            let parent_id = if parent_collection == this_collection {
+                self.data::<NestingData>().parent_of.get(&child_nft)
+            } else if !Self::env().is_contract(&parent_collection) {
+                None
+            } else {
+                match NestingRef::get_parent_of_child_builder(&parent_collection, child_nft.clone())
+                    .call_flags(CallFlags::default().set_allow_reentry(true))
+                    .try_invoke()
+                {
+                    Ok(Ok(parent_id)) => parent_id,
+                    _ => None,
+                }
+            };
+            let parent_id = match parent_id {
+                Some(parent_id) => parent_id,
+                None => break,
+            };
+
+            owner = if parent_collection == this_collection {
+                self.data::<psp34::Data<enumerable::Balances>>()
+                    .owner_of(parent_id.clone())
+            } else {
+                match PSP34Ref::owner_of_builder(&parent_collection, parent_id.clone())
+                    .call_flags(CallFlags::default().set_allow_reentry(true))
+                    .try_invoke()
+                {
+                    Ok(Ok(owner)) => owner,
+                    _ => None,
+                }
+            };
+            child_nft = (parent_collection, parent_id);
+            ancestors.push(child_nft.clone());
+        }
+        ancestors
+    }
+
+    /// Walk down the accepted children of the child nft, across collections, up to `limit` levels.
+    /// Collections which can't report the depth are taken as holding no children.
+    default fn children_depth_of(&self, child_nft: &ChildNft, limit: u32) -> u32 {
+        if limit == 0 {
+            return 0
+        }
+        if child_nft.0 != Self::env().account_id() {
+            if !Self::env().is_contract(&child_nft.0) {
+                return 0
+            }
+            return match NestingRef::children_depth_builder(
+                &child_nft.0,
+                child_nft.1.clone(),
+                limit,
+            )
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+            {
+                Ok(Ok(depth)) => depth.min(limit),
+                _ => 0,
+            }
+        }
+
+        let count = self.children_count(&child_nft.1, ChildStatus::Accepted);
+        let mut depth = 0;
+        for child in self.children_page(&child_nft.1, ChildStatus::Accepted, 0, count) {
+            depth = depth.max(self.children_depth_of(&child, limit - 1) + 1);
+            if depth >= limit {
+                break
+            }
+        }
+        depth
+    }
+}
+
+/// Append the child at the end of the children with the status, in the indexed layout.
+/// Returns false if the child is already there.
+fn push_child_entry(
+    nesting: &mut NestingData,
+    parent_token_id: &Id,
+    status: ChildStatus,
+    child_nft: &ChildNft,
+) -> bool {
+    if nesting
+        .child_index
+        .contains((parent_token_id, status, child_nft))
+    {
+        return false
+    }
+    let count = nesting
+        .children_count
+        .get((parent_token_id, status))
+        .unwrap_or_default();
+    nesting
+        .children
+        .insert((parent_token_id, status, count), child_nft);
+    nesting
+        .child_index
+        .insert((parent_token_id, status, child_nft), &count);
+    nesting
+        .children_count
+        .insert((parent_token_id, status), &(count + 1));
+
+    let collection_count = nesting
+        .collection_children_count
+        .get((parent_token_id, &child_nft.0))
+        .unwrap_or_default();
+    nesting
+        .collection_children_count
+        .insert((parent_token_id, &child_nft.0), &(collection_count + 1));
+    true
 }
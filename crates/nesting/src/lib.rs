@@ -7,7 +7,11 @@ pub mod internal;
 pub mod traits;
 
 use rmrk_common::{
-    errors::Result,
+    errors::{
+        Result,
+        RmrkError,
+    },
+    roles::ADMIN,
     types::*,
     utils::Utils,
 };
@@ -15,31 +19,90 @@ use rmrk_common::{
 use internal::Internal;
 
 use traits::{
+    ChildCollections,
     Nesting,
     NestingEvents,
+    NestingHooks,
+    NestingLimits,
+    NestingMigration,
+    NestingRef,
+    Ownership,
+    OwnershipRef,
 };
 
 use ink::{
+    env::CallFlags,
     prelude::vec::Vec,
     storage::Mapping,
 };
 
 use openbrush::{
-    contracts::psp34::extensions::enumerable::*,
+    contracts::{
+        access_control::*,
+        psp34::extensions::enumerable::*,
+    },
+    modifiers,
     traits::{
         AccountId,
         Storage,
+        Timestamp,
     },
 };
 
 pub const STORAGE_NESTING_KEY: u32 = openbrush::storage_unique_key!(NestingData);
 
+/// Maximum number of parent levels above a child when no depth limit is set
+pub const MAX_NESTING_DEPTH: u32 = 16;
+
+/// Maximum number of pending children returned to their senders on a parent transfer
+pub const MAX_CHILDREN_RETURNED_ON_TRANSFER: u64 = 16;
+
+/// Status of a child nft in its parent token
+#[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ChildStatus {
+    Accepted,
+    Pending,
+}
+
+/// Sender of a pending child nft and the time it was added
+#[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ChildOrigin {
+    pub sender: AccountId,
+    pub timestamp: Timestamp,
+}
+
 #[derive(Default, Debug)]
 #[openbrush::upgradeable_storage(STORAGE_NESTING_KEY)]
 pub struct NestingData {
+    /// Legacy layout of pending children,
+    /// moved to `children` on first change or by `migrate_children`
     pub pending_children: Mapping<Id, Vec<ChildNft>>,
+    /// Legacy layout of accepted children,
+    /// moved to `children` on first change or by `migrate_children`
     pub accepted_children: Mapping<Id, Vec<ChildNft>>,
     pub parent_of: Mapping<ChildNft, Id>,
+    pub max_accepted_children: Option<u64>,
+    pub max_pending_children: Option<u64>,
+    pub max_depth: Option<u32>,
+    /// Only approved collections can add children if set, any collection otherwise
+    pub restrict_child_collections: bool,
+    pub approved_child_collections: Mapping<AccountId, Option<u64>>,
+    pub pending_child_origin: Mapping<ChildNft, ChildOrigin>,
+    pub children_count: Mapping<(Id, ChildStatus), u64>,
+    pub children: Mapping<(Id, ChildStatus, u64), ChildNft>,
+    pub child_index: Mapping<(Id, ChildStatus, ChildNft), u64>,
+    pub collection_children_count: Mapping<(Id, AccountId), u64>,
+    pub pending_child_timeout: Option<Timestamp>,
+    /// Children which could not be returned on reject or burn, by the account that can claim them
+    pub claimable_children: Mapping<ChildNft, AccountId>,
 }
 
 impl<T> Nesting for T
@@ -47,17 +110,21 @@ where
     T: Storage<NestingData> + Storage<psp34::Data<enumerable::Balances>> + Utils,
 {
     /// Add a child NFT (from different collection) to the NFT in this collection
-    /// The status of the added child is `Pending` if caller is not owner of child NFT
-    /// The status of the added child is `Accepted` if caller is is owner of child NFT
+    /// The status of the added child is `Pending` if caller is not the owner of the parent NFT
+    /// The status of the added child is `Accepted` if caller is the owner, or the root owner,
+    /// of the parent NFT
     /// The caller needs not to be the owner of the to_parent_token_id, but
     /// Caller must be owner of the child NFT,
     /// in order to perform transfer() ownership of the child nft to to_parent_token_id.
     ///
     /// # Requirements:
-    /// * `child_contract_address` needs to be added by collecion owner
+    /// * Child collection must be approved, unless any child collection is allowed.
+    /// * Number of children from the child collection must be under its limit.
     /// * `to_parent_token_id` must exist.
     /// * `child_token_id` must exist.
+    /// * Caller must own the child or be approved to transfer it.
     /// * There cannot be two identical children.
+    /// * A soulbound child can only be added by the owner, or the root owner, of `to_parent_token_id`.
     ///
     /// # Arguments:
     /// * `to_parent_token_id`: is the tokenId of the parent NFT. The receiver of child.
@@ -66,24 +133,43 @@ where
     /// # Result:
     /// Ownership of child NFT will be transferred to this contract (cross contract call)
     /// On success emitts `RmrkEvent::ChildAdded`
-    /// On success emitts `RmrkEvent::ChildAccepted` - only if caller is already owner of child NFT
+    /// On success emitts `RmrkEvent::ChildAccepted` - only if caller owns the parent NFT
     default fn add_child(&mut self, to_parent_token_id: Id, child_nft: ChildNft) -> Result<()> {
-        let parent_owner = self.ensure_exists_and_get_owner(&to_parent_token_id)?;
+        self.ensure_exists_and_get_owner(&to_parent_token_id)?;
         self.accepted(&to_parent_token_id, &child_nft)?;
         self.pending(&to_parent_token_id, &child_nft)?;
+        self.ensure_child_collection_approved(&to_parent_token_id, &child_nft.0)?;
+        let caller = Self::env().caller();
+        let accepted = self
+            .is_caller_parent_owner(caller, &to_parent_token_id)
+            .is_ok();
+        if !accepted && self.is_child_locked(&child_nft) {
+            return Err(RmrkError::CannotTransferSoulbound.into())
+        }
+        self.ensure_nesting_limits(&to_parent_token_id, &child_nft, accepted)?;
+
+        // Record the child owner, a rejected or reclaimed child is returned to it
+        // and not to an operator adding the child on its behalf
+        let child_owner = self.child_owner(&child_nft)?;
+        self.ensure_child_operator(caller, child_owner, &child_nft)?;
+        self.call_nesting_hooks(&to_parent_token_id, &child_nft)?;
+
+        // The parent of an accepted child is recorded before the transfer,
+        // so that the child collection can verify the new root owner of the child
+        if accepted {
+            self.set_parent(&child_nft, to_parent_token_id.clone());
+        }
 
         // Transfer child ownership to this contract.
-        // This transfer call will fail if caller is not child owner
+        // This transfer call will fail if this contract is not approved by child owner
         self.transfer_child_ownership(Self::env().account_id(), child_nft.clone())?;
 
         // Insert child nft and emit event
         self._emit_added_child_event(&to_parent_token_id, &child_nft.0, &child_nft.1);
-        let caller = Self::env().caller();
-        if caller == parent_owner {
-            self.set_parent(&child_nft, to_parent_token_id.clone());
+        if accepted {
             self.add_to_accepted(to_parent_token_id, child_nft);
         } else {
-            self.add_to_pending(to_parent_token_id, child_nft);
+            self.add_to_pending(to_parent_token_id, child_nft, child_owner);
         }
 
         Ok(())
@@ -109,13 +195,14 @@ where
         self.is_caller_parent_owner(caller, &parent_token_id)?;
 
         // Remove child nft
-        self.remove_parent(&child_nft);
         self.remove_accepted(&parent_token_id, &child_nft)?;
 
         // Transfer child ownership from this contract to parent_token owner.
-        // This call will fail if this contract is not child owner
-        let token_owner = self.ensure_exists_and_get_owner(&parent_token_id)?;
-        self.transfer_child_ownership(token_owner, child_nft)?;
+        // This call will fail if this contract is not child owner.
+        // The parent is removed after the transfer, so that the child collection
+        // can verify that the root owner of the child is kept
+        self.transfer_child_ownership(caller, child_nft.clone())?;
+        self.remove_parent(&child_nft);
 
         Ok(())
     }
@@ -137,6 +224,8 @@ where
         let caller = Self::env().caller();
         self.is_caller_parent_owner(caller, &parent_token_id)?;
         self.accepted(&parent_token_id, &child_nft)?;
+        self.ensure_nesting_limits(&parent_token_id, &child_nft, true)?;
+        self.call_nesting_hooks(&parent_token_id, &child_nft)?;
 
         self.remove_from_pending(&parent_token_id, &child_nft)?;
 
@@ -156,7 +245,9 @@ where
     /// * `child_nft`: (collection_id, token_id) of the child instance.
     ///
     /// # Result:
-    /// Child Nft is removed from pending
+    /// Child Nft is removed from pending and returned to its sender (cross contract call)
+    /// If the child can't be returned, its sender can claim it later with `claim_child`.
+    /// Children added before senders were recorded are removed and stay with this contract.
     /// On success emitts `RmrkEvent::ChildRejected`
     default fn reject_child(&mut self, parent_token_id: Id, child_nft: ChildNft) -> Result<()> {
         self.ensure_exists_and_get_owner(&parent_token_id)?;
@@ -164,16 +255,85 @@ where
         self.is_caller_parent_owner(caller, &parent_token_id)?;
         self.accepted(&parent_token_id, &child_nft)?;
 
-        self.remove_from_pending(&parent_token_id, &child_nft)?;
+        let origin = self.remove_from_pending(&parent_token_id, &child_nft)?;
         self._emit_child_rejected_event(&parent_token_id, &child_nft.0, &child_nft.1);
 
+        // Children added before senders were recorded stay with this contract
+        if let Some(origin) = origin {
+            self.return_child(origin.sender, child_nft);
+        }
+
         Ok(())
     }
 
+    /// Reclaim a pending child NFT by its sender, once the pending child timeout elapsed
+    ///
+    /// # Requirements:
+    /// * The status of the child is `Pending`
+    /// * Caller is the sender of the child.
+    /// * Pending child timeout is set and elapsed since the child was added.
+    ///
+    /// # Arguments:
+    /// * `parent_token_id`: is the tokenId of the parent NFT.
+    /// * `child_nft`: (collection_id, token_id) of the child instance.
+    ///
+    /// # Result:
+    /// Child Nft is removed from pending and returned to its sender (cross contract call)
+    /// On success emitts `RmrkEvent::ChildReclaimed`
+    default fn reclaim_child(&mut self, parent_token_id: Id, child_nft: ChildNft) -> Result<()> {
+        let origin = self
+            .data::<NestingData>()
+            .pending_child_origin
+            .get(&child_nft)
+            .ok_or(RmrkError::ChildNotFound)?;
+        if origin.sender != Self::env().caller() {
+            return Err(RmrkError::NotChildSender.into())
+        }
+        let timeout = self
+            .data::<NestingData>()
+            .pending_child_timeout
+            .ok_or(RmrkError::PendingChildNotExpired)?;
+        if Self::env().block_timestamp() < origin.timestamp.saturating_add(timeout) {
+            return Err(RmrkError::PendingChildNotExpired.into())
+        }
+
+        self.remove_from_pending(&parent_token_id, &child_nft)?;
+        self._emit_child_reclaimed_event(&parent_token_id, &child_nft.0, &child_nft.1);
+        self.transfer_child_ownership(origin.sender, child_nft)
+    }
+
+    /// Claim a child NFT which could not be returned to the caller on reject or burn
+    default fn claim_child(&mut self, child_nft: ChildNft) -> Result<()> {
+        let claimant = self
+            .data::<NestingData>()
+            .claimable_children
+            .get(&child_nft)
+            .ok_or(RmrkError::ChildNotFound)?;
+        if claimant != Self::env().caller() {
+            return Err(RmrkError::NotChildSender.into())
+        }
+        self.transfer_child_ownership(claimant, child_nft.clone())?;
+        self.data::<NestingData>()
+            .claimable_children
+            .remove(&child_nft);
+        Ok(())
+    }
+
+    /// Returns the account which can claim the child NFT
+    fn get_child_claimant(&self, child_nft: ChildNft) -> Option<AccountId> {
+        self.data::<NestingData>()
+            .claimable_children
+            .get(&child_nft)
+    }
+
     /// Transfer the child NFT from one parent to another (in this collection)
+    /// The child is `Accepted` if both parents have the same owner, `Pending` otherwise.
+    /// A pending child has no parent until it is accepted.
     ///
     /// # Requirements:
+    /// * Caller is the owner, or the root owner, of the current parent.
     /// * The status of the child is `Accepted`
+    /// * A soulbound child can only be transferred between parents of the same owner.
     ///
     /// # Arguments:
     /// * `current_parent`: current parent tokenId which holds child nft
@@ -191,7 +351,17 @@ where
         child_nft: ChildNft,
     ) -> Result<()> {
         let current_parent_owner = self.ensure_exists_and_get_owner(&current_parent)?;
+        self.is_caller_parent_owner(Self::env().caller(), &current_parent)?;
         let new_parent_owner = self.ensure_exists_and_get_owner(&new_parent)?;
+        self.ensure_nesting_limits(
+            &new_parent,
+            &child_nft,
+            current_parent_owner == new_parent_owner,
+        )?;
+        if current_parent_owner != new_parent_owner && self.is_child_locked(&child_nft) {
+            return Err(RmrkError::CannotTransferSoulbound.into())
+        }
+        self.call_nesting_hooks(&new_parent, &child_nft)?;
         self.remove_accepted(&current_parent, &child_nft)?;
 
         self._emit_added_child_event(&new_parent, &child_nft.0, &child_nft.1);
@@ -199,12 +369,155 @@ where
             self.set_parent(&child_nft, new_parent.clone());
             self.add_to_accepted(new_parent, child_nft);
         } else {
-            self.add_to_pending(new_parent, child_nft);
+            self.remove_parent(&child_nft);
+            self.add_to_pending(new_parent, child_nft, current_parent_owner);
+        }
+
+        Ok(())
+    }
+
+    /// Transfer the child NFT from the parent in this collection to a parent in another collection
+    ///
+    /// # Requirements:
+    /// * Caller is the owner of the current parent.
+    /// * The status of the child is `Accepted`
+    /// * Destination collection supports nesting.
+    ///
+    /// # Arguments:
+    /// * `current_parent`: current parent tokenId which holds child nft
+    /// * `dest_collection`: collection of the new parent
+    /// * `dest_parent`: new parent tokenId in the destination collection
+    /// * `child_nft`: (collection_id, token_id) of the child instance.
+    ///
+    /// # Result:
+    /// Ownership of child NFT will be transferred to destination collection (cross contract call)
+    /// On success emitts `RmrkEvent::ChildRemoved`
+    default fn transfer_child_to_collection(
+        &mut self,
+        current_parent: Id,
+        dest_collection: AccountId,
+        dest_parent: Id,
+        child_nft: ChildNft,
+    ) -> Result<()> {
+        let current_parent_owner = self.ensure_exists_and_get_owner(&current_parent)?;
+        self.is_caller_parent_owner(Self::env().caller(), &current_parent)?;
+        if dest_collection == Self::env().account_id() {
+            return self.transfer_child(current_parent, dest_parent, child_nft)
+        }
+
+        if self.is_child_locked(&child_nft) {
+            let dest_parent_owner = match PSP34Ref::owner_of_builder(
+                &dest_collection,
+                dest_parent.clone(),
+            )
+            .try_invoke()
+            {
+                Ok(Ok(Some(owner))) => owner,
+                _ => return Err(RmrkError::InvalidParentId.into()),
+            };
+            if dest_parent_owner != current_parent_owner {
+                return Err(RmrkError::CannotTransferSoulbound.into())
+            }
         }
+        self.remove_accepted(&current_parent, &child_nft)?;
 
+        // Destination pulls the child and lands it under the new parent in one call.
+        // The parent is removed after the transfer, so that the child collection
+        // can verify that the root owner of the child is kept
+        self.approve_child_transfer(dest_collection, &child_nft)?;
+        let sender = self.root_owner(current_parent)?;
+        match NestingRef::add_transferred_child_builder(
+            &dest_collection,
+            dest_parent,
+            child_nft.clone(),
+            sender,
+        )
+        .call_flags(CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        {
+            Ok(Ok(result)) => result?,
+            Ok(Err(err)) => return Err(RmrkError::ChildTransferFailed(err.into()).into()),
+            Err(err) => return Err(RmrkError::ChildTransferFailed(err.into()).into()),
+        }
+        self.remove_parent(&child_nft);
         Ok(())
     }
 
+    /// Add a child NFT transferred from a parent in the calling collection
+    ///
+    /// # Requirements:
+    /// * `to_parent_token_id` must exist.
+    /// * Child NFT must be owned by the caller, which approved this contract to transfer it.
+    /// * `sender` must be the root owner of the child, as resolved by the child collection.
+    /// * There cannot be two identical children.
+    ///
+    /// # Arguments:
+    /// * `to_parent_token_id`: is the tokenId of the parent NFT. The receiver of child.
+    /// * `child_nft`: (collection_id, token_id) of the child instance.
+    /// * `sender`: root owner of the previous parent of the child.
+    ///
+    /// # Result:
+    /// Ownership of child NFT will be transferred to this contract (cross contract call)
+    /// On success emitts `RmrkEvent::ChildAdded`
+    /// On success emitts `RmrkEvent::ChildAccepted` - only if sender is owner of parent NFT
+    default fn add_transferred_child(
+        &mut self,
+        to_parent_token_id: Id,
+        child_nft: ChildNft,
+        sender: AccountId,
+    ) -> Result<()> {
+        let parent_owner = self.ensure_exists_and_get_owner(&to_parent_token_id)?;
+        if self.child_owner(&child_nft)? != Self::env().caller() {
+            return Err(RmrkError::ChildNotOwnedByCaller.into())
+        }
+        // The sender is reported by the calling collection,
+        // the child collection must resolve it as the root owner of the child
+        if self.child_root_owner(&child_nft)? != sender {
+            return Err(RmrkError::NotChildSender.into())
+        }
+        if sender != parent_owner && self.is_child_locked(&child_nft) {
+            return Err(RmrkError::CannotTransferSoulbound.into())
+        }
+
+        // The child is registered before the transfer,
+        // so that the child collection can verify its new root owner
+        self.register_child(to_parent_token_id, child_nft.clone(), sender)?;
+        self.transfer_child_ownership(Self::env().account_id(), child_nft)
+    }
+
+    /// Add a freshly minted child NFT to the NFT in this collection
+    ///
+    /// # Requirements:
+    /// * `to_parent_token_id` must exist.
+    /// * Child NFT must be owned by this contract.
+    /// * There cannot be two identical children.
+    ///
+    /// # Arguments:
+    /// * `to_parent_token_id`: is the tokenId of the parent NFT. The receiver of child.
+    /// * `child_token_id`: tokenId of the child in the calling collection.
+    ///
+    /// # Result:
+    /// On success emitts `RmrkEvent::ChildAdded`
+    default fn add_minted_child(
+        &mut self,
+        to_parent_token_id: Id,
+        child_token_id: Id,
+    ) -> Result<()> {
+        let child_nft = (Self::env().caller(), child_token_id);
+
+        // The child collection must have minted the child to this contract.
+        // Reentrant call, the child collection is expected to allow it
+        match PSP34Ref::owner_of_builder(&child_nft.0, child_nft.1.clone()).try_invoke() {
+            Ok(Ok(Some(owner))) if owner == Self::env().account_id() => (),
+            _ => return Err(RmrkError::ChildNotOwnedByParent.into()),
+        }
+
+        // The minter reported by the child collection couldn't be verified,
+        // so a rejected child goes back to the child collection instead.
+        let child_collection = child_nft.0;
+        self.register_pending_child(to_parent_token_id, child_nft, child_collection)
+    }
+
     /// Read the number of children on the parent token
     /// # Arguments:
     /// * `parent_token_id`: parent tokenId to check
@@ -213,48 +526,319 @@ where
     /// Returns the tupple of `(accepted_children, pending_children)` count
     fn children_balance(&self, parent_token_id: Id) -> Result<(u64, u64)> {
         self.ensure_exists_and_get_owner(&parent_token_id)?;
-        let parents_with_accepted_children = match self
-            .data::<NestingData>()
-            .accepted_children
-            .get(&parent_token_id)
-        {
-            Some(children) => children.len() as u64,
-            None => 0,
+        Ok((
+            self.children_count(&parent_token_id, ChildStatus::Accepted),
+            self.children_count(&parent_token_id, ChildStatus::Pending),
+        ))
+    }
+
+    /// Get a page of pending children for parent token_id
+    fn get_pending_children(&self, parent_token_id: Id, offset: u64, limit: u64) -> Vec<ChildNft> {
+        self.children_page(&parent_token_id, ChildStatus::Pending, offset, limit)
+    }
+
+    /// Get a page of accepted children for parent token_id
+    fn get_accepted_children(&self, parent_token_id: Id, offset: u64, limit: u64) -> Vec<ChildNft> {
+        self.children_page(&parent_token_id, ChildStatus::Accepted, offset, limit)
+    }
+
+    fn get_parent_of_child(&self, child_nft: ChildNft) -> Option<Id> {
+        self.data::<NestingData>().parent_of.get(&child_nft)
+    }
+
+    fn children_depth(&self, token_id: Id, limit: u32) -> u32 {
+        self.children_depth_of(&(Self::env().account_id(), token_id), limit)
+    }
+
+    /// Return the pending children of the token transferred to a new owner to their senders.
+    /// At most `MAX_CHILDREN_RETURNED_ON_TRANSFER` children are handled per transfer.
+    /// The others, along with children without a recorded sender or which can't be returned,
+    /// stay pending for the new owner to accept or reject, so that they can't block the transfer.
+    /// Accepted children stay nested and follow the token to its new owner.
+    default fn on_parent_transfer(&mut self, token_id: &Id) -> Result<()> {
+        let limit = self
+            .children_count(token_id, ChildStatus::Pending)
+            .min(MAX_CHILDREN_RETURNED_ON_TRANSFER);
+        for child_nft in self.children_page(token_id, ChildStatus::Pending, 0, limit) {
+            let origin = self
+                .data::<NestingData>()
+                .pending_child_origin
+                .get(&child_nft);
+            let sender = match origin {
+                Some(origin) => origin.sender,
+                None => continue,
+            };
+            if self
+                .transfer_child_ownership(sender, child_nft.clone())
+                .is_ok()
+                && self.remove_from_pending(token_id, &child_nft).is_ok()
+            {
+                self._emit_child_rejected_event(token_id, &child_nft.0, &child_nft.1);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> NestingLimits for T
+where
+    T: Storage<NestingData> + Storage<access_control::Data>,
+{
+    /// Set limits for children count and nesting depth
+    #[modifiers(only_role(ADMIN))]
+    default fn set_nesting_limits(
+        &mut self,
+        max_accepted_children: Option<u64>,
+        max_pending_children: Option<u64>,
+        max_depth: Option<u32>,
+    ) -> Result<()> {
+        if max_depth > Some(MAX_NESTING_DEPTH) {
+            return Err(RmrkError::BadConfig.into())
+        }
+        let nesting = self.data::<NestingData>();
+        nesting.max_accepted_children = max_accepted_children;
+        nesting.max_pending_children = max_pending_children;
+        nesting.max_depth = max_depth;
+        Ok(())
+    }
+
+    /// Get limits for children count and nesting depth
+    default fn nesting_limits(&self) -> (Option<u64>, Option<u64>, Option<u32>) {
+        let nesting = self.data::<NestingData>();
+        (
+            nesting.max_accepted_children,
+            nesting.max_pending_children,
+            nesting.max_depth,
+        )
+    }
+
+    /// Set the time after which senders can reclaim their pending children
+    #[modifiers(only_role(ADMIN))]
+    default fn set_pending_child_timeout(&mut self, timeout: Option<Timestamp>) -> Result<()> {
+        self.data::<NestingData>().pending_child_timeout = timeout;
+        Ok(())
+    }
+
+    /// Get the time after which senders can reclaim their pending children
+    default fn pending_child_timeout(&self) -> Option<Timestamp> {
+        self.data::<NestingData>().pending_child_timeout
+    }
+}
+
+impl<T> NestingMigration for T
+where
+    T: Storage<NestingData>
+        + Storage<psp34::Data<enumerable::Balances>>
+        + Storage<access_control::Data>,
+{
+    /// Move children of a batch of tokens from the legacy vector layout to the indexed one
+    #[modifiers(only_role(ADMIN))]
+    default fn migrate_children(&mut self, offset: u128, limit: u128) -> Result<u64> {
+        let mut migrated = 0;
+        for index in offset..offset.saturating_add(limit) {
+            let parent_token_id = match PSP34Enumerable::token_by_index(self, index) {
+                Ok(parent_token_id) => parent_token_id,
+                Err(_) => break,
+            };
+            migrated += self.migrate_legacy_children(&parent_token_id);
+        }
+        Ok(migrated)
+    }
+}
+
+impl<T> ChildCollections for T
+where
+    T: Storage<NestingData> + Storage<access_control::Data>,
+{
+    /// Approve the child collection, with an optional limit of children per parent token
+    #[modifiers(only_role(ADMIN))]
+    default fn approve_child_collection(
+        &mut self,
+        collection: AccountId,
+        max_children: Option<u64>,
+    ) -> Result<()> {
+        self.data::<NestingData>()
+            .approved_child_collections
+            .insert(&collection, &max_children);
+        Ok(())
+    }
+
+    /// Revoke the approval of the child collection
+    #[modifiers(only_role(ADMIN))]
+    default fn revoke_child_collection(&mut self, collection: AccountId) -> Result<()> {
+        self.data::<NestingData>()
+            .approved_child_collections
+            .remove(&collection);
+        Ok(())
+    }
+
+    /// Allow or disallow children from any collection
+    #[modifiers(only_role(ADMIN))]
+    default fn set_allow_any_child_collection(&mut self, allow_any: bool) -> Result<()> {
+        self.data::<NestingData>().restrict_child_collections = !allow_any;
+        Ok(())
+    }
+
+    /// Check if children from any collection are allowed
+    default fn allow_any_child_collection(&self) -> bool {
+        !self.data::<NestingData>().restrict_child_collections
+    }
+
+    /// Get the approval and the limit of children per parent token for the child collection
+    default fn child_collection(&self, collection: AccountId) -> (bool, Option<u64>) {
+        let nesting = self.data::<NestingData>();
+        match nesting.approved_child_collections.get(&collection) {
+            Some(max_children) => (true, max_children),
+            None => (!nesting.restrict_child_collections, None),
+        }
+    }
+}
+
+impl<T> Ownership for T
+where
+    T: Storage<NestingData> + Storage<psp34::Data<enumerable::Balances>>,
+{
+    /// Get the direct owner of the token
+    default fn direct_owner(&self, token_id: Id) -> Result<(AccountId, Option<Id>, bool)> {
+        let owner = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .owner_of(token_id.clone())
+            .ok_or(PSP34Error::TokenNotExists)?;
+        let this_collection = Self::env().account_id();
+        let child_nft = (this_collection, token_id);
+        let parent_id = if owner == this_collection {
+            self.data::<NestingData>().parent_of.get(&child_nft)
+        } else if !Self::env().is_contract(&owner) {
+            None
+        } else {
+            match NestingRef::get_parent_of_child_builder(&owner, child_nft)
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke()
+            {
+                Ok(Ok(parent_id)) => parent_id,
+                _ => None,
+            }
+        };
+        let is_nft = parent_id.is_some();
+        Ok((owner, parent_id, is_nft))
+    }
+
+    /// Walk up the parents of the token, across collections, to the top-level owner
+    default fn root_owner(&self, token_id: Id) -> Result<AccountId> {
+        let this_collection = Self::env().account_id();
+        let (mut owner, mut parent_id, _) = self.direct_owner(token_id)?;
+        for _ in 0..MAX_NESTING_DEPTH {
+            let token_id = match parent_id {
+                Some(token_id) => token_id,
+                None => return Ok(owner),
+            };
+            (owner, parent_id, _) = if owner == this_collection {
+                self.direct_owner(token_id)?
+            } else {
+                match OwnershipRef::direct_owner_builder(&owner, token_id)
+                    .call_flags(CallFlags::default().set_allow_reentry(true))
+                    .try_invoke()
+                {
+                    Ok(Ok(result)) => result?,
+                    _ => return Err(RmrkError::RootOwnerNotFound.into()),
+                }
+            };
+        }
+        Err(RmrkError::MaxNestingDepthReached.into())
+    }
+
+    /// Ensure that the account is the direct or the root owner of the token
+    default fn ensure_root_owner(&self, account: AccountId, token_id: &Id) -> Result<()> {
+        let owner = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .owner_of(token_id.clone())
+            .ok_or(PSP34Error::TokenNotExists)?;
+        if owner != account && self.root_owner(token_id.clone())? != account {
+            return Err(RmrkError::NotTokenOwner.into())
+        }
+        Ok(())
+    }
+
+    /// Ensure that the token keeps its root owner when it is transferred to `to`
+    /// Only contracts can hold the token as a child, other accounts are root owners themselves.
+    /// Other collections must be on the approved child collections, since the root owner
+    /// they report is trusted.
+    default fn ensure_root_owner_kept(&self, to: &AccountId, token_id: &Id) -> Result<()> {
+        let owner = self
+            .data::<psp34::Data<enumerable::Balances>>()
+            .owner_of(token_id.clone())
+            .ok_or(PSP34Error::TokenNotExists)?;
+        let root_owner = if Self::env().is_contract(&owner) {
+            self.root_owner(token_id.clone())?
+        } else {
+            owner
         };
 
-        let parents_with_pending_children = match self
+        let this_collection = Self::env().account_id();
+        let child_nft = (this_collection, token_id.clone());
+        let new_root_owner = if *to == this_collection {
+            match self.data::<NestingData>().parent_of.get(&child_nft) {
+                Some(parent_id) => self.root_owner(parent_id)?,
+                None => *to,
+            }
+        } else if !Self::env().is_contract(to) {
+            *to
+        } else if !self
             .data::<NestingData>()
-            .pending_children
-            .get(&parent_token_id)
+            .approved_child_collections
+            .contains(to)
         {
-            Some(children) => children.len() as u64,
-            None => 0,
+            return Err(RmrkError::CannotTransferSoulbound.into())
+        } else {
+            match NestingRef::get_parent_of_child_builder(to, child_nft)
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke()
+            {
+                Ok(Ok(Some(parent_id))) => {
+                    match OwnershipRef::root_owner_builder(to, parent_id)
+                        .call_flags(CallFlags::default().set_allow_reentry(true))
+                        .try_invoke()
+                    {
+                        Ok(Ok(result)) => result?,
+                        _ => return Err(RmrkError::RootOwnerNotFound.into()),
+                    }
+                }
+                _ => *to,
+            }
         };
-
-        Ok((
-            parents_with_accepted_children,
-            parents_with_pending_children,
-        ))
+        if new_root_owner != root_owner {
+            return Err(RmrkError::CannotTransferSoulbound.into())
+        }
+        Ok(())
     }
+}
 
-    /// Get all pending children for parent token_id
-    fn get_pending_children(&self, parent_token_id: Id) -> Vec<ChildNft> {
-        self.data::<NestingData>()
-            .pending_children
-            .get(parent_token_id)
-            .unwrap_or_default()
+impl<T> NestingHooks for T
+where
+    T: Storage<NestingData> + Storage<psp34::Data<enumerable::Balances>>,
+{
+    /// Run the hook for the calling parent collection
+    default fn on_nested(&mut self, parent_token_id: Id, child_token_id: Id) -> Result<()> {
+        self._on_nested(Self::env().caller(), &parent_token_id, &child_token_id)
     }
 
-    /// Get all accepted children for parent token_id
-    fn get_accepted_children(&self, parent_token_id: Id) -> Vec<ChildNft> {
-        self.data::<NestingData>()
-            .accepted_children
-            .get(parent_token_id)
-            .unwrap_or_default()
+    /// Consent to nesting by default
+    default fn _on_nested(
+        &mut self,
+        _parent_collection: AccountId,
+        _parent_token_id: &Id,
+        _child_token_id: &Id,
+    ) -> Result<()> {
+        Ok(())
     }
 
-    fn get_parent_of_child(&self, child_nft: ChildNft) -> Option<Id> {
-        self.data::<NestingData>().parent_of.get(&child_nft)
+    /// Accept every child by default
+    default fn on_child_received(
+        &mut self,
+        _parent_token_id: &Id,
+        _child_nft: &ChildNft,
+    ) -> Result<()> {
+        Ok(())
     }
 }
 
@@ -297,4 +881,13 @@ where
         _child_token_id: &Id,
     ) {
     }
+
+    /// Emit ChildReclaimed event
+    default fn _emit_child_reclaimed_event(
+        &self,
+        _parent: &Id,
+        _child_collection_address: &AccountId,
+        _child_token_id: &Id,
+    ) {
+    }
 }
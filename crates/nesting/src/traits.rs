@@ -7,7 +7,10 @@ use rmrk_common::{
 use ink::prelude::vec::Vec;
 use openbrush::{
     contracts::psp34::Id,
-    traits::AccountId,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
 };
 
 #[openbrush::wrapper]
@@ -17,8 +20,9 @@ pub type NestingRef = dyn Nesting;
 #[openbrush::trait_definition]
 pub trait Nesting {
     /// Add a child NFT (from different collection) to the NFT in this collection.
-    /// The status of the added child is `Pending` if caller is not owner of child NFT
-    /// The status of the added child is `Accepted` if caller is is owner of child NFT
+    /// The status of the added child is `Pending` if caller is not the owner of the parent NFT
+    /// The status of the added child is `Accepted` if caller is the owner, or the root owner,
+    /// of the parent NFT
     /// The caller needs not to be the owner of the to_parent_token_id, but
     /// Caller must be owner of the child NFT,
     /// in order to perform transfer() ownership of the child nft to to_parent_token_id.
@@ -27,6 +31,7 @@ pub trait Nesting {
     /// * `child_contract_address` needs to be added by collecion owner
     /// * `to_parent_token_id` must exist.
     /// * `child_token_id` must exist.
+    /// * Caller must own the child or be approved to transfer it.
     /// * There cannot be two identical children.
     ///
     /// # Arguments:
@@ -82,14 +87,52 @@ pub trait Nesting {
     /// * `child_nft`: (collection_id, token_id) of the child instance.
     ///
     /// # Result:
-    /// Child Nft is removed from pending
+    /// Child Nft is removed from pending and returned to its sender (cross contract call)
+    /// If the child can't be returned, its sender can claim it later with `claim_child`.
+    /// Children added before senders were recorded are removed and stay with this contract.
     /// On success emitts `RmrkEvent::ChildRejected`
     #[ink(message)]
     fn reject_child(&mut self, parent_token_id: Id, child_nft: ChildNft) -> Result<()>;
 
+    /// Reclaim a pending child NFT by its sender, once the pending child timeout elapsed.
+    ///
+    /// # Requirements:
+    /// * The status of the child is `Pending`
+    /// * Caller is the sender of the child.
+    /// * Pending child timeout is set and elapsed since the child was added.
+    ///
+    /// # Arguments:
+    /// * `parent_token_id`: is the tokenId of the parent NFT.
+    /// * `child_nft`: (collection_id, token_id) of the child instance.
+    ///
+    /// # Result:
+    /// Child Nft is removed from pending and returned to its sender (cross contract call)
+    /// On success emitts `RmrkEvent::ChildReclaimed`
+    #[ink(message)]
+    fn reclaim_child(&mut self, parent_token_id: Id, child_nft: ChildNft) -> Result<()>;
+
+    /// Claim a child NFT which could not be returned to the caller on reject or burn.
+    ///
+    /// # Requirements:
+    /// * Caller is the account the child was returned to.
+    ///
+    /// # Arguments:
+    /// * `child_nft`: (collection_id, token_id) of the child instance.
+    ///
+    /// # Result:
+    /// Child Nft is transferred to the caller (cross contract call)
+    #[ink(message)]
+    fn claim_child(&mut self, child_nft: ChildNft) -> Result<()>;
+
+    /// Returns the account which can claim the child NFT with `claim_child`.
+    #[ink(message)]
+    fn get_child_claimant(&self, child_nft: ChildNft) -> Option<AccountId>;
+
     /// Transfer the child NFT from one parent to another (in this collection).
+    /// The child is `Accepted` if both parents have the same owner, `Pending` otherwise.
     ///
     /// # Requirements:
+    /// * Caller is the owner, or the root owner, of the current parent.
     /// * The status of the child is `Accepted`
     ///
     /// # Arguments:
@@ -104,6 +147,79 @@ pub trait Nesting {
     #[ink(message)]
     fn transfer_child(&mut self, from: Id, to: Id, child_nft: ChildNft) -> Result<()>;
 
+    /// Transfer the child NFT from the parent in this collection to a parent in another collection.
+    ///
+    /// # Requirements:
+    /// * Caller is the owner of the current parent.
+    /// * The status of the child is `Accepted`
+    /// * Destination collection supports nesting.
+    ///
+    /// # Arguments:
+    /// * `current_parent`: current parent tokenId which holds child nft
+    /// * `dest_collection`: collection of the new parent
+    /// * `dest_parent`: new parent tokenId in the destination collection
+    /// * `child_nft`: (collection_id, token_id) of the child instance.
+    ///
+    /// # Result:
+    /// Ownership of child NFT will be transferred to destination collection (cross contract call)
+    /// On success emitts `RmrkEvent::ChildRemoved`
+    /// The child is accepted by the destination if the owners of both parents are the same.
+    #[ink(message)]
+    fn transfer_child_to_collection(
+        &mut self,
+        current_parent: Id,
+        dest_collection: AccountId,
+        dest_parent: Id,
+        child_nft: ChildNft,
+    ) -> Result<()>;
+
+    /// Add a child NFT transferred from a parent in the calling collection.
+    /// Called by the source collection on `transfer_child_to_collection`.
+    /// The status of the added child is `Accepted` if sender is owner of the parent NFT,
+    /// `Pending` otherwise.
+    ///
+    /// # Requirements:
+    /// * `to_parent_token_id` must exist.
+    /// * Child NFT must be owned by the caller, which approved this contract to transfer it.
+    /// * `sender` must be the root owner of the child, as resolved by the child collection.
+    /// * There cannot be two identical children.
+    ///
+    /// # Arguments:
+    /// * `to_parent_token_id`: is the tokenId of the parent NFT. The receiver of child.
+    /// * `child_nft`: (collection_id, token_id) of the child instance.
+    /// * `sender`: root owner of the previous parent of the child.
+    ///
+    /// # Result:
+    /// Ownership of child NFT will be transferred to this contract (cross contract call)
+    /// On success emitts `RmrkEvent::ChildAdded`
+    #[ink(message)]
+    fn add_transferred_child(
+        &mut self,
+        to_parent_token_id: Id,
+        child_nft: ChildNft,
+        sender: AccountId,
+    ) -> Result<()>;
+
+    /// Add a freshly minted child NFT to the NFT in this collection.
+    /// Called by the child collection on `nest_mint`, after minting the child to this contract.
+    /// The status of the added child is always `Pending`, since the minter can't be verified.
+    /// The parent NFT owner accepts or rejects the child. A rejected child is returned
+    /// to the child collection itself, which owns it from then on.
+    ///
+    /// # Requirements:
+    /// * `to_parent_token_id` must exist.
+    /// * Child NFT must be owned by this contract.
+    /// * There cannot be two identical children.
+    ///
+    /// # Arguments:
+    /// * `to_parent_token_id`: is the tokenId of the parent NFT. The receiver of child.
+    /// * `child_token_id`: tokenId of the child in the calling collection.
+    ///
+    /// # Result:
+    /// On success emitts `RmrkEvent::ChildAdded`
+    #[ink(message)]
+    fn add_minted_child(&mut self, to_parent_token_id: Id, child_token_id: Id) -> Result<()>;
+
     /// Read the number of children on the parent token.
     /// # Arguments:
     /// * `parent_token_id`: parent tokenId to check
@@ -113,19 +229,212 @@ pub trait Nesting {
     #[ink(message)]
     fn children_balance(&self, parent_token_id: Id) -> Result<(u64, u64)>;
 
-    /// Get all pending children for parent token_id
+    /// Get a page of pending children for parent token_id.
+    ///
+    /// # Arguments:
+    /// * `parent_token_id`: parent tokenId to check
+    /// * `offset`: index of the first child to return
+    /// * `limit`: maximum number of children to return
     #[ink(message)]
-    fn get_pending_children(&self, parent_token_id: Id) -> Vec<ChildNft>;
+    fn get_pending_children(&self, parent_token_id: Id, offset: u64, limit: u64) -> Vec<ChildNft>;
 
-    /// Get all accepted children for parent token_id
+    /// Get a page of accepted children for parent token_id.
+    ///
+    /// # Arguments:
+    /// * `parent_token_id`: parent tokenId to check
+    /// * `offset`: index of the first child to return
+    /// * `limit`: maximum number of children to return
     #[ink(message)]
-    fn get_accepted_children(&self, parent_token_id: Id) -> Vec<ChildNft>;
+    fn get_accepted_children(&self, parent_token_id: Id, offset: u64, limit: u64) -> Vec<ChildNft>;
 
     /// Returns the parent token id of the provided child nft.
     ///
     /// Only works if the child and parent are in the same collection.
     #[ink(message)]
     fn get_parent_of_child(&self, child_nft: ChildNft) -> Option<Id>;
+
+    /// Returns the number of levels of accepted children below the token, across collections.
+    /// Levels are counted up to `limit`.
+    #[ink(message)]
+    fn children_depth(&self, token_id: Id, limit: u32) -> u32;
+
+    /// Update the children of the token transferred to a new owner.
+    /// To be called from `psp34::Internal::_before_token_transfer`.
+    /// Handles at most `MAX_CHILDREN_RETURNED_ON_TRANSFER` pending children of the token.
+    ///
+    /// # Result:
+    /// Pending children are returned to their senders. Children over the cap, or which
+    /// can't be returned, stay pending for the new owner. Accepted children follow the token.
+    /// On success emitts `RmrkEvent::ChildRejected` for each returned child
+    fn on_parent_transfer(&mut self, token_id: &Id) -> Result<()>;
+}
+
+#[openbrush::wrapper]
+pub type NestingLimitsRef = dyn NestingLimits;
+
+/// Trait definitions for Nesting limits
+#[openbrush::trait_definition]
+pub trait NestingLimits {
+    /// Set limits for children count and nesting depth. `None` stands for no limit.
+    /// Burning a parent token costs a storage access for each of its children,
+    /// so the children limits also bound the cost of that call.
+    ///
+    /// # Arguments:
+    /// * `max_accepted_children`: maximum number of accepted children per parent token
+    /// * `max_pending_children`: maximum number of pending children per parent token
+    /// * `max_depth`: maximum number of parent levels above a child, across collections.
+    ///   Without the limit, the depth is capped at `MAX_NESTING_DEPTH` levels, which is
+    ///   also the highest allowed limit.
+    #[ink(message)]
+    fn set_nesting_limits(
+        &mut self,
+        max_accepted_children: Option<u64>,
+        max_pending_children: Option<u64>,
+        max_depth: Option<u32>,
+    ) -> Result<()>;
+
+    /// Get limits for children count and nesting depth.
+    ///
+    /// # Result:
+    /// Returns the tupple of `(max_accepted_children, max_pending_children, max_depth)`
+    #[ink(message)]
+    fn nesting_limits(&self) -> (Option<u64>, Option<u64>, Option<u32>);
+
+    /// Set the time, in milliseconds, after which senders can reclaim their pending children.
+    /// `None` stands for no reclaim.
+    #[ink(message)]
+    fn set_pending_child_timeout(&mut self, timeout: Option<Timestamp>) -> Result<()>;
+
+    /// Get the time, in milliseconds, after which senders can reclaim their pending children.
+    #[ink(message)]
+    fn pending_child_timeout(&self) -> Option<Timestamp>;
+}
+
+#[openbrush::wrapper]
+pub type NestingMigrationRef = dyn NestingMigration;
+
+/// Trait definitions for migration of Nesting storage
+#[openbrush::trait_definition]
+pub trait NestingMigration {
+    /// Move children from the legacy layout, one vector per parent, to the indexed layout.
+    /// Children of a parent token not migrated yet are read from the legacy layout,
+    /// and moved on the first change of its children, so calling this is optional.
+    /// Tokens are walked by their enumeration index, from `offset` up to `limit` tokens,
+    /// so that all tokens can be migrated in batches up to `total_supply`.
+    ///
+    /// # Result:
+    /// Returns the number of migrated children
+    #[ink(message)]
+    fn migrate_children(&mut self, offset: u128, limit: u128) -> Result<u64>;
+}
+
+#[openbrush::wrapper]
+pub type ChildCollectionsRef = dyn ChildCollections;
+
+/// Trait definitions for collections allowed to add children to this collection.
+/// Children from any collection are allowed by default, limits of approved collections apply.
+/// Children from this collection are always allowed.
+#[openbrush::trait_definition]
+pub trait ChildCollections {
+    /// Approve the child collection. Updates the limit if already approved.
+    ///
+    /// # Arguments:
+    /// * `collection`: address of the child collection
+    /// * `max_children`: maximum number of accepted and pending children
+    ///   from the child collection per parent token. `None` stands for no limit.
+    #[ink(message)]
+    fn approve_child_collection(
+        &mut self,
+        collection: AccountId,
+        max_children: Option<u64>,
+    ) -> Result<()>;
+
+    /// Revoke the approval of the child collection.
+    /// Children already added are kept, and can still be removed or rejected.
+    #[ink(message)]
+    fn revoke_child_collection(&mut self, collection: AccountId) -> Result<()>;
+
+    /// Allow children from any collection, approved or not, which is the default.
+    /// Limits of approved collections still apply.
+    #[ink(message)]
+    fn set_allow_any_child_collection(&mut self, allow_any: bool) -> Result<()>;
+
+    /// Check if children from any collection are allowed.
+    #[ink(message)]
+    fn allow_any_child_collection(&self) -> bool;
+
+    /// Get the approval of the child collection.
+    ///
+    /// # Result:
+    /// Returns the tupple of `(approved, max_children)`
+    #[ink(message)]
+    fn child_collection(&self, collection: AccountId) -> (bool, Option<u64>);
+}
+
+#[openbrush::wrapper]
+pub type OwnershipRef = dyn Ownership;
+
+/// Trait definitions for ownership of nested tokens.
+/// A nested token is owned by the contract of its parent collection.
+/// The root owner is the account owning the top-level token of the tree.
+#[openbrush::trait_definition]
+pub trait Ownership {
+    /// Get the direct owner of the token.
+    ///
+    /// # Result:
+    /// Returns the tupple of `(owner, parent_token_id, is_nft)`.
+    /// If the token is an accepted child, `owner` is the parent collection
+    /// and `parent_token_id` is the parent token in that collection.
+    #[ink(message)]
+    fn direct_owner(&self, token_id: Id) -> Result<(AccountId, Option<Id>, bool)>;
+
+    /// Get the owner of the top-level token the token is nested in, across collections.
+    #[ink(message)]
+    fn root_owner(&self, token_id: Id) -> Result<AccountId>;
+
+    /// Ensure that the account is the direct or the root owner of the token.
+    fn ensure_root_owner(&self, account: AccountId, token_id: &Id) -> Result<()>;
+
+    /// Ensure that the token keeps its root owner when it is transferred to `to`.
+    /// If `to` is a collection which records the token as a child, the root owner
+    /// of its parent token is compared. Other collections than this one must be
+    /// approved child collections.
+    fn ensure_root_owner_kept(&self, to: &AccountId, token_id: &Id) -> Result<()>;
+}
+
+#[openbrush::wrapper]
+pub type NestingHooksRef = dyn NestingHooks;
+
+/// Trait definitions for Nesting hooks.
+/// Both hooks are called when a child is added to a parent, as pending or accepted,
+/// and again when a pending child is accepted. Either side can veto it with an error.
+#[openbrush::trait_definition]
+pub trait NestingHooks {
+    /// Called by the parent collection when a token of this collection is added to a parent.
+    /// The caller is the collection of the parent NFT.
+    ///
+    /// # Arguments:
+    /// * `parent_token_id`: is the tokenId of the parent NFT.
+    /// * `child_token_id`: is the tokenId of the nested NFT in this collection.
+    ///
+    /// # Result:
+    /// The child is not added if an error is returned.
+    /// Child collections without this message consent to nesting.
+    #[ink(message)]
+    fn on_nested(&mut self, parent_token_id: Id, child_token_id: Id) -> Result<()>;
+
+    /// Called on the child collection when its token is added to a parent.
+    /// The child is not added if an error is returned.
+    fn _on_nested(
+        &mut self,
+        parent_collection: AccountId,
+        parent_token_id: &Id,
+        child_token_id: &Id,
+    ) -> Result<()>;
+
+    /// Called on the parent collection when a child is added to the parent.
+    /// The child is not added if an error is returned.
+    fn on_child_received(&mut self, parent_token_id: &Id, child_nft: &ChildNft) -> Result<()>;
 }
 
 /// Trait definitions for Nesting ink events
@@ -157,4 +466,12 @@ pub trait NestingEvents {
         child_collection_address: &AccountId,
         child_token_id: &Id,
     );
+
+    /// Emit ChildReclaimed event.
+    fn _emit_child_reclaimed_event(
+        &self,
+        parent: &Id,
+        child_collection_address: &AccountId,
+        child_token_id: &Id,
+    );
 }
@@ -0,0 +1,126 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod rmrk_contract_nesting_hooks {
+    use openbrush::{
+        contracts::{
+            access_control::*,
+            psp34::extensions::enumerable::*,
+            reentrancy_guard::*,
+        },
+        traits::Storage,
+    };
+
+    use rmrk_common::{
+        errors::{
+            Result,
+            RmrkError,
+        },
+        types::*,
+    };
+    use rmrk_nesting::{
+        traits::*,
+        NestingData,
+    };
+
+    /// Token which refuses to be nested
+    pub const FREE_TOKEN_ID: Id = Id::U64(4);
+
+    // Rmrk contract storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Rmrk {
+        #[storage_field]
+        psp34: psp34::Data<enumerable::Balances>,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        nesting: NestingData,
+        received_children: u32,
+    }
+
+    impl PSP34 for Rmrk {}
+
+    impl Nesting for Rmrk {}
+
+    impl NestingHooks for Rmrk {
+        /// Veto nesting of the free token
+        fn _on_nested(
+            &mut self,
+            _parent_collection: AccountId,
+            _parent_token_id: &Id,
+            child_token_id: &Id,
+        ) -> Result<()> {
+            if *child_token_id == FREE_TOKEN_ID {
+                return Err(RmrkError::CannotTransferSoulbound.into())
+            }
+            Ok(())
+        }
+
+        /// Count the children received by the parents
+        fn on_child_received(
+            &mut self,
+            _parent_token_id: &Id,
+            _child_nft: &ChildNft,
+        ) -> Result<()> {
+            self.received_children += 1;
+            Ok(())
+        }
+    }
+
+    impl Rmrk {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Rmrk::default()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+        use rmrk_nesting::internal::Internal;
+
+        #[ink::test]
+        fn nesting_hooks_react_and_veto() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let collection = test::callee::<ink::env::DefaultEnvironment>();
+            let mut rmrk = Rmrk::new();
+            for id in 1..3 {
+                assert!(psp34::Internal::_mint_to(&mut rmrk, accounts.bob, Id::U64(id)).is_ok());
+            }
+            for id in 3..5 {
+                assert!(psp34::Internal::_mint_to(&mut rmrk, collection, Id::U64(id)).is_ok());
+            }
+
+            // Parent collection counts added and transferred children
+            let child = (collection, Id::U64(3));
+            assert!(rmrk
+                .register_child(Id::U64(1), child.clone(), accounts.bob)
+                .is_ok());
+            assert_eq!(rmrk.received_children, 1);
+            test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(rmrk
+                .transfer_child(Id::U64(1), Id::U64(2), child.clone())
+                .is_ok());
+            assert_eq!(rmrk.received_children, 2);
+            assert_eq!(rmrk.get_accepted_children(Id::U64(2), 0, 10), vec![child]);
+
+            // Child collection vetoes the free token, as accepted or pending child
+            let free_child = (collection, FREE_TOKEN_ID);
+            assert_eq!(
+                rmrk.register_child(Id::U64(1), free_child.clone(), accounts.bob),
+                Err(RmrkError::CannotTransferSoulbound.into())
+            );
+            assert_eq!(
+                rmrk.register_child(Id::U64(1), free_child, accounts.charlie),
+                Err(RmrkError::CannotTransferSoulbound.into())
+            );
+            assert_eq!(rmrk.children_balance(Id::U64(1)), Ok((0, 0)));
+            assert_eq!(rmrk.received_children, 2);
+        }
+    }
+}
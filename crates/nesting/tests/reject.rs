@@ -0,0 +1,228 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod rmrk_contract_nesting_reject {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        contracts::{
+            access_control::*,
+            psp34::extensions::enumerable::*,
+            reentrancy_guard::*,
+        },
+        traits::Storage,
+    };
+
+    use rmrk_common::{
+        errors::{
+            Result,
+            RmrkError,
+        },
+        types::*,
+    };
+    use rmrk_nesting::{
+        internal::Internal,
+        traits::*,
+        NestingData,
+    };
+
+    /// Child token which the mocked child collection fails to transfer
+    pub const STUCK_CHILD_ID: Id = Id::U64(0);
+
+    /// Event emitted by the mocked child collection when a child is transferred.
+    #[ink(event)]
+    pub struct ChildTransfer {
+        #[ink(topic)]
+        to: AccountId,
+        child_nft: ChildNft,
+    }
+
+    // Rmrk contract storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Rmrk {
+        #[storage_field]
+        psp34: psp34::Data<enumerable::Balances>,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        nesting: NestingData,
+    }
+
+    impl PSP34 for Rmrk {}
+
+    impl Nesting for Rmrk {}
+
+    impl Internal for Rmrk {
+        /// Mock the child collection, record the transfer instead of calling it
+        fn transfer_child_ownership(&self, to: AccountId, child_nft: ChildNft) -> Result<()> {
+            if child_nft.1 == STUCK_CHILD_ID {
+                return Err(RmrkError::ChildTransferFailed(PSP34Error::NotApproved.into()).into())
+            }
+            self.env().emit_event(ChildTransfer { to, child_nft });
+            Ok(())
+        }
+
+        /// Mock the child collection, consent to nesting without calling it
+        fn call_nesting_hooks(
+            &mut self,
+            _parent_token_id: &Id,
+            _child_nft: &ChildNft,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Rmrk {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Rmrk::default()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+        use rmrk_nesting::MAX_CHILDREN_RETURNED_ON_TRANSFER;
+
+        fn returned_children() -> Vec<(AccountId, ChildNft)> {
+            test::recorded_events()
+                .map(|event| {
+                    let Event::ChildTransfer(transfer) =
+                        <Event as scale::Decode>::decode(&mut &event.data[..])
+                            .expect("encountered invalid contract event data buffer");
+                    (transfer.to, transfer.child_nft)
+                })
+                .collect()
+        }
+
+        #[ink::test]
+        fn reject_child_returns_child_to_its_origin() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut rmrk = Rmrk::new();
+            let child_collection = accounts.django;
+            assert!(psp34::Internal::_mint_to(&mut rmrk, accounts.alice, Id::U64(1)).is_ok());
+
+            // charlie's child is pending on alice's parent
+            let child_nft = (child_collection, Id::U64(7));
+            assert!(rmrk
+                .register_pending_child(Id::U64(1), child_nft.clone(), accounts.charlie)
+                .is_ok());
+
+            // Only the parent owner can reject
+            test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                rmrk.reject_child(Id::U64(1), child_nft.clone()),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+            assert!(returned_children().is_empty());
+
+            // Rejected child goes back to charlie, not to the rejecting parent owner
+            test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(rmrk.reject_child(Id::U64(1), child_nft.clone()), Ok(()));
+            assert_eq!(returned_children(), vec![(accounts.charlie, child_nft)]);
+            assert_eq!(rmrk.children_balance(Id::U64(1)), Ok((0, 0)));
+        }
+
+        #[ink::test]
+        fn reject_child_without_origin_keeps_child() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut rmrk = Rmrk::new();
+            assert!(psp34::Internal::_mint_to(&mut rmrk, accounts.alice, Id::U64(1)).is_ok());
+
+            // Child added before senders were recorded
+            let child_nft = (accounts.django, Id::U64(7));
+            rmrk.nesting
+                .pending_children
+                .insert(Id::U64(1), &vec![child_nft.clone()]);
+
+            assert_eq!(rmrk.reject_child(Id::U64(1), child_nft), Ok(()));
+            assert!(returned_children().is_empty());
+            assert_eq!(rmrk.children_balance(Id::U64(1)), Ok((0, 0)));
+        }
+
+        #[ink::test]
+        fn reject_child_keeps_unreturned_child_claimable() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut rmrk = Rmrk::new();
+            assert!(psp34::Internal::_mint_to(&mut rmrk, accounts.alice, Id::U64(1)).is_ok());
+
+            // Rejecting a child which can't be returned still removes it from the parent
+            let stuck_child = (accounts.django, STUCK_CHILD_ID);
+            assert!(rmrk
+                .register_pending_child(Id::U64(1), stuck_child.clone(), accounts.charlie)
+                .is_ok());
+            assert_eq!(rmrk.reject_child(Id::U64(1), stuck_child.clone()), Ok(()));
+            assert!(returned_children().is_empty());
+            assert_eq!(rmrk.children_balance(Id::U64(1)), Ok((0, 0)));
+            assert_eq!(
+                rmrk.get_child_claimant(stuck_child.clone()),
+                Some(accounts.charlie)
+            );
+
+            // Only charlie can claim it, a failed claim keeps it claimable
+            assert_eq!(
+                rmrk.claim_child(stuck_child.clone()),
+                Err(RmrkError::NotChildSender.into())
+            );
+            test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                rmrk.claim_child(stuck_child.clone()),
+                Err(RmrkError::ChildTransferFailed(PSP34Error::NotApproved.into()).into())
+            );
+            assert_eq!(rmrk.get_child_claimant(stuck_child), Some(accounts.charlie));
+
+            let child_nft = (accounts.django, Id::U64(7));
+            rmrk.nesting
+                .claimable_children
+                .insert(&child_nft, &accounts.charlie);
+            assert_eq!(rmrk.claim_child(child_nft.clone()), Ok(()));
+            assert_eq!(
+                returned_children(),
+                vec![(accounts.charlie, child_nft.clone())]
+            );
+            assert_eq!(rmrk.get_child_claimant(child_nft), None);
+        }
+
+        #[ink::test]
+        fn parent_transfer_returns_capped_pending_children() {
+            let accounts = test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut rmrk = Rmrk::new();
+            let child_collection = accounts.django;
+            assert!(psp34::Internal::_mint_to(&mut rmrk, accounts.alice, Id::U64(1)).is_ok());
+
+            // The stuck child comes first, followed by one more child than the cap
+            let stuck_child = (child_collection, STUCK_CHILD_ID);
+            assert!(rmrk
+                .register_pending_child(Id::U64(1), stuck_child.clone(), accounts.charlie)
+                .is_ok());
+            for id in 1..=MAX_CHILDREN_RETURNED_ON_TRANSFER {
+                let child_nft = (child_collection, Id::U64(id));
+                assert!(rmrk
+                    .register_pending_child(Id::U64(1), child_nft, accounts.charlie)
+                    .is_ok());
+            }
+
+            // The stuck child is skipped, the child over the cap stays pending
+            assert_eq!(rmrk.on_parent_transfer(&Id::U64(1)), Ok(()));
+            let returned: Vec<_> = (1..MAX_CHILDREN_RETURNED_ON_TRANSFER)
+                .map(|id| (accounts.charlie, (child_collection, Id::U64(id))))
+                .collect();
+            assert_eq!(returned_children(), returned);
+            assert_eq!(rmrk.children_balance(Id::U64(1)), Ok((0, 2)));
+            assert_eq!(
+                rmrk.get_pending_children(Id::U64(1), 0, 10),
+                vec![
+                    stuck_child,
+                    (child_collection, Id::U64(MAX_CHILDREN_RETURNED_ON_TRANSFER))
+                ]
+            );
+        }
+    }
+}
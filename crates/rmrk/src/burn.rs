@@ -0,0 +1,164 @@
+#![allow(clippy::inline_fn_without_body)]
+
+/// Burning of RMRK tokens.
+/// Burning a token also cleans up its assets, equipment and children,
+/// so no orphaned entries are left in the storage of other RMRK crates.
+use crate::storage::*;
+
+use ink::env::CallFlags;
+use openbrush::{
+    contracts::psp34::extensions::enumerable::*,
+    traits::{
+        DefaultEnv,
+        Storage,
+    },
+};
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    utils::Utils,
+};
+use rmrk_equippable::traits::EquippableEvents;
+use rmrk_nesting::internal::Internal as NestingInternal;
+
+#[openbrush::wrapper]
+pub type BurnableRef = dyn Burnable;
+
+#[openbrush::trait_definition]
+pub trait Burnable:
+    DefaultEnv
+    + NestingInternal
+    + EquippableEvents
+    + Storage<NestingData>
+    + Storage<MultiAssetData>
+    + Storage<EquippableData>
+    + Storage<psp34::Data<enumerable::Balances>>
+    + psp34::Internal
+    + Utils
+{
+    /// Burn a token together with its assets, equipment and children.
+    ///
+    /// # Requirements:
+    /// * Caller must be the token owner.
+    /// * Accepted children are burned recursively. Their count, including their own
+    ///   children, must not exceed `max_recursive_burns`.
+    ///
+    /// # Arguments:
+    /// * `token_id`: Id of the token to burn.
+    /// * `max_recursive_burns`: Maximum number of children that can be burned.
+    ///   With 0, the burn fails if the token has accepted children.
+    ///
+    /// # Result:
+    /// Pending and accepted assets are removed and all slots are unequipped.
+    /// Pending children are returned to their senders, or released to the token owner
+    /// if the sender was not recorded. A child which can't be returned is left for
+    /// its receiver to claim with `Nesting::claim_child`.
+    /// Returns the number of burned children.
+    #[ink(message)]
+    fn burn(&mut self, token_id: Id, max_recursive_burns: u32) -> Result<u32> {
+        self._burn_token(token_id, Some(max_recursive_burns))
+    }
+
+    /// Burn a token together with its assets and equipment, releasing its children.
+    ///
+    /// # Requirements:
+    /// * Caller must be the token owner.
+    ///
+    /// # Arguments:
+    /// * `token_id`: Id of the token to burn.
+    ///
+    /// # Result:
+    /// Pending and accepted assets are removed and all slots are unequipped.
+    /// Pending children are returned as on `burn`, accepted children are transferred
+    /// to the token owner and only the token itself is burned.
+    #[ink(message)]
+    fn burn_and_release(&mut self, token_id: Id) -> Result<()> {
+        self._burn_token(token_id, None).map(|_| ())
+    }
+
+    /// Burn a token, burning accepted children recursively within `max_recursive_burns`,
+    /// or releasing them to the token owner if there is no budget.
+    /// Returns the number of burned children.
+    fn _burn_token(&mut self, token_id: Id, max_recursive_burns: Option<u32>) -> Result<u32> {
+        let token_owner = self.ensure_exists_and_get_owner(&token_id)?;
+        self.ensure_token_owner(token_owner)?;
+
+        // Unequip all slots of the token's accepted assets
+        let accepted_assets = self
+            .data::<MultiAssetData>()
+            .accepted_assets
+            .take(&token_id)
+            .unwrap_or_default();
+        for asset_id in accepted_assets {
+            if let Some(asset) = self
+                .data::<MultiAssetData>()
+                .collection_asset_entries
+                .get(asset_id)
+            {
+                for part_id in asset.part_ids {
+                    if self
+                        .data::<EquippableData>()
+                        .equipment
+                        .take((token_id.clone(), part_id))
+                        .is_some()
+                    {
+                        self.emit_child_asset_unequipped(token_id.clone(), asset_id, part_id);
+                    }
+                }
+            }
+        }
+        self.data::<MultiAssetData>()
+            .pending_assets
+            .remove(&token_id);
+
+        // Return pending children to their senders
+        let pending_children = self.take_children(&token_id, ChildStatus::Pending);
+        for child_nft in pending_children {
+            let receiver = self
+                .data::<NestingData>()
+                .pending_child_origin
+                .take(&child_nft)
+                .map_or(token_owner, |origin| origin.sender);
+            self.return_child(receiver, child_nft);
+        }
+
+        // Burn accepted children, or release them if there is no burn budget
+        let accepted_children = self.take_children(&token_id, ChildStatus::Accepted);
+        let mut total_child_burns: u32 = 0;
+        for child_nft in accepted_children {
+            let max_recursive_burns = match max_recursive_burns {
+                Some(max_recursive_burns) => max_recursive_burns,
+                None => {
+                    // The parent is removed after the transfer,
+                    // so that the child collection can verify that the root owner is kept
+                    self.transfer_child_ownership(token_owner, child_nft.clone())?;
+                    self.remove_parent(&child_nft);
+                    continue
+                }
+            };
+            self.remove_parent(&child_nft);
+            if total_child_burns >= max_recursive_burns {
+                return Err(RmrkError::MaxRecursiveBurnsReached.into())
+            }
+
+            let child_burns = match BurnableRef::burn_builder(
+                &child_nft.0,
+                child_nft.1,
+                max_recursive_burns - total_child_burns - 1,
+            )
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+            {
+                Ok(Ok(result)) => result?,
+                _ => return Err(RmrkError::ChildBurnFailed.into()),
+            };
+            total_child_burns = total_child_burns.saturating_add(child_burns.saturating_add(1));
+        }
+
+        psp34::Internal::_burn_from(self, token_owner, token_id)?;
+
+        Ok(total_child_burns)
+    }
+}
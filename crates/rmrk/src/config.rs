@@ -13,14 +13,20 @@ use openbrush::{
         StorageAsMut,
         StorageAsRef,
         String,
+        Timestamp,
     },
 };
 
-use rmrk_common::roles::CONTRIBUTOR;
+use rmrk_common::{
+    counter::Counter,
+    errors::Result,
+    roles::CONTRIBUTOR,
+};
 use rmrk_minting::{
     self,
     traits::MintingLazy,
 };
+use rmrk_royalty::internal::Internal as RoyaltyInternal;
 
 pub fn with_collection<T>(
     instance: &mut T,
@@ -55,6 +61,72 @@ where
     minting.price_per_mint = price_per_mint;
 }
 
+pub fn with_payment_token<T>(instance: &mut T, payment_token: AccountId)
+where
+    T: Storage<rmrk_minting::MintingData>,
+{
+    let minting: &mut rmrk_minting::MintingData = <T as StorageAsMut>::data(instance);
+
+    minting.payment_token = Some(payment_token);
+}
+
+pub fn with_mint_limits<T>(
+    instance: &mut T,
+    max_mint_per_wallet: Option<u64>,
+    max_mint_per_call: Option<u64>,
+) where
+    T: Storage<rmrk_minting::MintingData>,
+{
+    let minting: &mut rmrk_minting::MintingData = <T as StorageAsMut>::data(instance);
+
+    minting.max_mint_per_wallet = max_mint_per_wallet;
+    minting.max_mint_per_call = max_mint_per_call;
+}
+
+pub fn with_token_uri_suffix<T>(instance: &mut T, suffix: String)
+where
+    T: Storage<rmrk_minting::MintingData>,
+{
+    let minting: &mut rmrk_minting::MintingData = <T as StorageAsMut>::data(instance);
+
+    minting.token_uri_suffix = Some(suffix);
+}
+
+pub fn with_token_id_variant<T>(instance: &mut T, initial: Id) -> Result<()>
+where
+    T: Storage<rmrk_minting::extensions::autoindex::MintingAutoIndexData>,
+{
+    let autoindex: &mut rmrk_minting::extensions::autoindex::MintingAutoIndexData =
+        <T as StorageAsMut>::data(instance);
+
+    autoindex.token_id = Counter::new(initial)?;
+    Ok(())
+}
+
+pub fn with_nesting_limits<T>(
+    instance: &mut T,
+    max_accepted_children: Option<u64>,
+    max_pending_children: Option<u64>,
+    max_depth: Option<u32>,
+) where
+    T: Storage<rmrk_nesting::NestingData>,
+{
+    let nesting: &mut rmrk_nesting::NestingData = <T as StorageAsMut>::data(instance);
+
+    nesting.max_accepted_children = max_accepted_children;
+    nesting.max_pending_children = max_pending_children;
+    nesting.max_depth = max_depth;
+}
+
+pub fn with_pending_child_timeout<T>(instance: &mut T, timeout: Timestamp)
+where
+    T: Storage<rmrk_nesting::NestingData>,
+{
+    let nesting: &mut rmrk_nesting::NestingData = <T as StorageAsMut>::data(instance);
+
+    nesting.pending_child_timeout = Some(timeout);
+}
+
 pub fn with_admin<T>(instance: &mut T, account: AccountId)
 where
     T: access_control::Internal + Storage<access_control::Data>,
@@ -70,6 +142,13 @@ where
     instance._setup_role(CONTRIBUTOR, account);
 }
 
-fn _with_royalties<T>(_instance: &mut T, _royalty_receiver: AccountId, _royalty: u8) {
-    todo!()
+pub fn with_royalties<T>(
+    instance: &mut T,
+    royalty_receiver: AccountId,
+    royalty_bps: u16,
+) -> Result<()>
+where
+    T: Storage<rmrk_royalty::RoyaltyData>,
+{
+    instance._set_royalty(royalty_receiver, royalty_bps)
 }
@@ -1,13 +1,17 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod batch;
+pub mod burn;
 pub mod config;
+pub mod minting;
 pub mod query;
 
 pub use rmrk_common::{
     counter,
     errors,
     roles,
+    soulbound,
+    splitter,
     types,
     utils,
 };
@@ -18,6 +22,7 @@ pub mod storage {
     pub use rmrk_minting::*;
     pub use rmrk_multiasset::*;
     pub use rmrk_nesting::*;
+    pub use rmrk_royalty::*;
 }
 
 pub mod traits {
@@ -26,10 +31,14 @@ pub mod traits {
     pub use rmrk_minting::traits::*;
     pub use rmrk_multiasset::traits::*;
     pub use rmrk_nesting::traits::*;
+    pub use rmrk_royalty::traits::*;
 }
 
 pub mod extensions {
     pub use rmrk_catalog::extensions::autoindex::*;
-    pub use rmrk_minting::extensions::autoindex::*;
+    pub use rmrk_minting::extensions::{
+        allowlist::*,
+        autoindex::*,
+    };
     pub use rmrk_multiasset::extensions::autoindex::*;
 }
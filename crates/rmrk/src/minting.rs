@@ -0,0 +1,161 @@
+#![allow(clippy::inline_fn_without_body)]
+
+/// Minting of tokens together with their assets, or directly into a parent NFT.
+/// Adding assets or nesting after minting needs additional Txs per token,
+/// which is costly given the big POV size of the RMRK contract.
+use crate::{
+    storage::*,
+    traits::*,
+};
+
+use ink::{
+    env::CallFlags,
+    prelude::vec::Vec,
+};
+use openbrush::{
+    contracts::psp34::extensions::enumerable::*,
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+        StorageAsRef,
+    },
+};
+use rmrk_common::{
+    errors::{
+        Result,
+        RmrkError,
+    },
+    types::*,
+};
+use rmrk_multiasset::internal::Internal as MultiAssetInternal;
+use rmrk_nesting::internal::Internal as NestingInternal;
+
+#[openbrush::wrapper]
+pub type MintingWithAssetsRef = dyn MintingWithAssets;
+
+#[openbrush::trait_definition]
+pub trait MintingWithAssets:
+    DefaultEnv + Minting + MultiAssetInternal + MultiAssetEvents + Storage<MultiAssetData>
+{
+    /// Mint a token and add the collection asset entries to it as accepted assets.
+    ///
+    /// # Requirements:
+    /// * Same as for `Minting::mint` and `MultiAsset::add_asset_to_token`.
+    /// * All `asset_ids` must be known and listed only once.
+    ///
+    /// # Arguments:
+    /// * `to`: Account receiving the token.
+    /// * `token_id`: Id of the token to mint.
+    /// * `asset_ids`: Ids of the collection asset entries to add.
+    #[ink(message)]
+    fn mint_with_assets(
+        &mut self,
+        to: AccountId,
+        token_id: Id,
+        asset_ids: Vec<AssetId>,
+    ) -> Result<()> {
+        ensure_assets_exist(self, &asset_ids)?;
+        Minting::mint(self, to, token_id.clone())?;
+        add_accepted_assets(self, &token_id, &asset_ids)
+    }
+}
+
+#[openbrush::wrapper]
+pub type MintingAutoIndexWithAssetsRef = dyn MintingAutoIndexWithAssets;
+
+#[openbrush::trait_definition]
+pub trait MintingAutoIndexWithAssets:
+    DefaultEnv + MintingAutoIndex + MultiAssetInternal + MultiAssetEvents + Storage<MultiAssetData>
+{
+    /// Mint a token with auto-generated Id and add the collection asset entries to it
+    /// as accepted assets. Returns the Id of the minted token.
+    ///
+    /// # Requirements:
+    /// * Same as for `MintingAutoIndex::mint` and `MultiAsset::add_asset_to_token`.
+    /// * All `asset_ids` must be known and listed only once.
+    #[ink(message)]
+    fn mint_next_with_assets(&mut self, to: AccountId, asset_ids: Vec<AssetId>) -> Result<Id> {
+        ensure_assets_exist(self, &asset_ids)?;
+        let token_id = MintingAutoIndex::mint(self, to)?;
+        add_accepted_assets(self, &token_id, &asset_ids)?;
+        Ok(token_id)
+    }
+}
+
+#[openbrush::wrapper]
+pub type NestMintingRef = dyn NestMinting;
+
+#[openbrush::trait_definition]
+pub trait NestMinting: DefaultEnv + Minting + NestingInternal + Storage<NestingData> {
+    /// Mint a token directly into a parent NFT.
+    ///
+    /// # Requirements:
+    /// * Same as for `Minting::mint`.
+    /// * `parent_token_id` must exist in `parent_collection`.
+    ///
+    /// # Arguments:
+    /// * `parent_collection`: Collection of the parent NFT. Can be this collection.
+    /// * `parent_token_id`: Id of the parent NFT.
+    /// * `token_id`: Id of the token to mint.
+    ///
+    /// # Result:
+    /// The token is minted to the parent collection and added to the parent NFT.
+    /// In this collection, it is accepted if the caller owns the parent NFT, pending otherwise.
+    /// In another collection, it is always pending until the parent NFT owner accepts it,
+    /// and a rejected token is returned to this collection.
+    #[ink(message)]
+    fn nest_mint(
+        &mut self,
+        parent_collection: AccountId,
+        parent_token_id: Id,
+        token_id: Id,
+    ) -> Result<()> {
+        let caller = Self::env().caller();
+        Minting::mint(self, parent_collection, token_id.clone())?;
+
+        if parent_collection == Self::env().account_id() {
+            return self.register_child(parent_token_id, (parent_collection, token_id), caller)
+        }
+
+        match NestingRef::add_minted_child_builder(&parent_collection, parent_token_id, token_id)
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+        {
+            Ok(Ok(result)) => result,
+            _ => Err(RmrkError::NestMintFailed.into()),
+        }
+    }
+}
+
+/// Check that all asset entries exist, before anything is minted
+fn ensure_assets_exist<T>(instance: &T, asset_ids: &[AssetId]) -> Result<()>
+where
+    T: Storage<MultiAssetData>,
+{
+    let multiasset: &MultiAssetData = <T as StorageAsRef>::data(instance);
+    for (index, asset_id) in asset_ids.iter().enumerate() {
+        multiasset
+            .collection_asset_entries
+            .get(asset_id)
+            .ok_or(RmrkError::AssetIdNotFound)?;
+        if asset_ids[..index].contains(asset_id) {
+            return Err(RmrkError::AlreadyAddedAsset.into())
+        }
+    }
+    Ok(())
+}
+
+/// Add the asset entries to the freshly minted token as accepted assets
+fn add_accepted_assets<T>(instance: &mut T, token_id: &Id, asset_ids: &[AssetId]) -> Result<()>
+where
+    T: MultiAssetInternal + MultiAssetEvents,
+{
+    for asset_id in asset_ids {
+        instance.ensure_not_accepted(token_id, asset_id)?;
+        instance.ensure_not_pending(token_id, asset_id)?;
+        instance._emit_asset_added_to_token_event(token_id, asset_id, &None);
+        instance.add_to_accepted_assets(token_id, asset_id);
+    }
+    Ok(())
+}
@@ -98,13 +98,13 @@ pub trait Query: DefaultEnv {
         );
 
         let children_pending = nested_result_unwrap_or_default(
-            NestingRef::get_pending_children_builder(&collection_id, id.clone())
+            NestingRef::get_pending_children_builder(&collection_id, id.clone(), 0, u64::MAX)
                 .call_flags(ink::env::CallFlags::default().set_allow_reentry(true))
                 .try_invoke(),
         );
 
         let children_accepted = nested_result_unwrap_or_default(
-            NestingRef::get_accepted_children_builder(&collection_id, id.clone())
+            NestingRef::get_accepted_children_builder(&collection_id, id.clone(), 0, u64::MAX)
                 .call_flags(ink::env::CallFlags::default().set_allow_reentry(true))
                 .try_invoke(),
         );
@@ -0,0 +1,51 @@
+use crate::RoyaltyData;
+
+use rmrk_common::errors::{
+    Result,
+    RmrkError,
+};
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+
+/// Royalty rate denominator. Rates are expressed in basis points.
+pub const ROYALTY_DENOMINATOR: u16 = 10_000;
+
+/// Trait definitions for Royalty internal functions.
+pub trait Internal {
+    /// Set the collection royalty receiver and rate.
+    fn _set_royalty(&mut self, receiver: AccountId, royalty_bps: u16) -> Result<()>;
+}
+
+impl<T> Internal for T
+where
+    T: Storage<RoyaltyData>,
+{
+    /// Set the collection royalty receiver and rate.
+    default fn _set_royalty(&mut self, receiver: AccountId, royalty_bps: u16) -> Result<()> {
+        ensure_valid_royalty(royalty_bps)?;
+        let royalty = self.data::<RoyaltyData>();
+        royalty.receiver = receiver;
+        royalty.royalty_bps = royalty_bps;
+        Ok(())
+    }
+}
+
+/// Ensure the royalty rate does not exceed 100%.
+pub fn ensure_valid_royalty(royalty_bps: u16) -> Result<()> {
+    if royalty_bps > ROYALTY_DENOMINATOR {
+        return Err(RmrkError::RoyaltyTooHigh.into())
+    }
+    Ok(())
+}
+
+/// Calculate the royalty amount without overflowing for large sale prices.
+pub fn royalty_amount(sale_price: Balance, royalty_bps: u16) -> Balance {
+    let denominator = ROYALTY_DENOMINATOR as Balance;
+    let royalty_bps = royalty_bps as Balance;
+    (sale_price / denominator) * royalty_bps
+        + (sale_price % denominator) * royalty_bps / denominator
+}
@@ -0,0 +1,91 @@
+//! RMRK Royalty implementation
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+#![allow(clippy::inline_fn_without_body)]
+
+pub mod internal;
+pub mod traits;
+
+use rmrk_common::{
+    errors::Result,
+    roles::ADMIN,
+};
+
+use internal::{
+    ensure_valid_royalty,
+    royalty_amount,
+    Internal,
+};
+use traits::Royalty;
+
+use ink::storage::Mapping;
+
+use openbrush::{
+    contracts::{
+        access_control::{
+            self,
+            only_role,
+        },
+        psp34::Id,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_ROYALTY_KEY: u32 = openbrush::storage_unique_key!(RoyaltyData);
+
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_ROYALTY_KEY)]
+pub struct RoyaltyData {
+    pub receiver: AccountId,
+    pub royalty_bps: u16,
+    pub token_royalty: Mapping<Id, (AccountId, u16)>,
+}
+
+impl<T> Royalty for T
+where
+    T: Storage<RoyaltyData> + Storage<access_control::Data>,
+{
+    /// Set the collection royalty receiver and rate.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_royalty(&mut self, receiver: AccountId, royalty_bps: u16) -> Result<()> {
+        self._set_royalty(receiver, royalty_bps)
+    }
+
+    /// Override the collection royalty for a single token.
+    #[modifiers(only_role(ADMIN))]
+    default fn set_token_royalty(
+        &mut self,
+        token_id: Id,
+        receiver: AccountId,
+        royalty_bps: u16,
+    ) -> Result<()> {
+        ensure_valid_royalty(royalty_bps)?;
+        self.data::<RoyaltyData>()
+            .token_royalty
+            .insert(token_id, &(receiver, royalty_bps));
+        Ok(())
+    }
+
+    /// Remove the royalty override of a token.
+    #[modifiers(only_role(ADMIN))]
+    default fn reset_token_royalty(&mut self, token_id: Id) -> Result<()> {
+        self.data::<RoyaltyData>().token_royalty.remove(token_id);
+        Ok(())
+    }
+
+    /// Get the royalty receiver and amount owed for the sale of a token.
+    default fn royalty_info(&self, token_id: Id, sale_price: Balance) -> (AccountId, Balance) {
+        let royalty = self.data::<RoyaltyData>();
+        let (receiver, royalty_bps) = royalty
+            .token_royalty
+            .get(token_id)
+            .unwrap_or((royalty.receiver, royalty.royalty_bps));
+
+        (receiver, royalty_amount(sale_price, royalty_bps))
+    }
+}
@@ -0,0 +1,54 @@
+//! Trait definitions for Royalty module
+use rmrk_common::errors::Result;
+
+use openbrush::{
+    contracts::psp34::Id,
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+
+#[openbrush::wrapper]
+pub type RoyaltyRef = dyn Royalty;
+
+/// Trait definitions for Royalty ink! messages
+#[openbrush::trait_definition]
+pub trait Royalty {
+    /// Set the collection royalty receiver and rate.
+    ///
+    /// # Arguments:
+    /// * `receiver`: account receiving the royalties.
+    /// * `royalty_bps`: royalty rate in basis points (1/100 of a percent).
+    #[ink(message)]
+    fn set_royalty(&mut self, receiver: AccountId, royalty_bps: u16) -> Result<()>;
+
+    /// Override the collection royalty for a single token.
+    ///
+    /// # Arguments:
+    /// * `token_id`: token to set the royalty for.
+    /// * `receiver`: account receiving the royalties.
+    /// * `royalty_bps`: royalty rate in basis points (1/100 of a percent).
+    #[ink(message)]
+    fn set_token_royalty(
+        &mut self,
+        token_id: Id,
+        receiver: AccountId,
+        royalty_bps: u16,
+    ) -> Result<()>;
+
+    /// Remove the royalty override of a token. The collection royalty applies again.
+    #[ink(message)]
+    fn reset_token_royalty(&mut self, token_id: Id) -> Result<()>;
+
+    /// Get the royalty receiver and amount owed for the sale of a token.
+    ///
+    /// # Arguments:
+    /// * `token_id`: token being sold.
+    /// * `sale_price`: price of the sale.
+    ///
+    /// # Result:
+    /// Returns the tuple of `(receiver, royalty_amount)`
+    #[ink(message)]
+    fn royalty_info(&self, token_id: Id, sale_price: Balance) -> (AccountId, Balance);
+}
@@ -0,0 +1,127 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+#[openbrush::contract]
+pub mod rmrk_contract_royalty {
+
+    use openbrush::{
+        contracts::{
+            access_control::*,
+            psp34::Id,
+        },
+        traits::Storage,
+    };
+
+    use rmrk_royalty::{
+        traits::*,
+        RoyaltyData,
+    };
+
+    // Rmrk contract storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Rmrk {
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        royalty: RoyaltyData,
+    }
+
+    impl AccessControl for Rmrk {}
+
+    impl Royalty for Rmrk {}
+
+    impl Rmrk {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Rmrk::default();
+            instance._init_with_admin(instance.env().caller());
+            instance
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+
+        use rmrk_common::errors::RmrkError;
+        use rmrk_royalty::internal::{
+            royalty_amount,
+            ROYALTY_DENOMINATOR,
+        };
+
+        const SALE_PRICE: Balance = 10_000;
+
+        fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            test::default_accounts::<Environment>()
+        }
+
+        fn set_sender(sender: AccountId) {
+            test::set_caller::<Environment>(sender);
+        }
+
+        #[ink::test]
+        fn collection_royalty_works() {
+            let accounts = default_accounts();
+            let mut rmrk = Rmrk::new();
+            assert_eq!(rmrk.royalty_info(Id::U64(1), SALE_PRICE).1, 0);
+
+            assert_eq!(rmrk.set_royalty(accounts.bob, 500), Ok(()));
+            assert_eq!(
+                rmrk.royalty_info(Id::U64(1), SALE_PRICE),
+                (accounts.bob, 500)
+            );
+            assert_eq!(
+                rmrk.set_royalty(accounts.bob, ROYALTY_DENOMINATOR + 1),
+                Err(RmrkError::RoyaltyTooHigh.into())
+            );
+
+            // Only admin can set royalty
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_royalty(accounts.bob, 100),
+                Err(AccessControlError::MissingRole.into())
+            );
+        }
+
+        #[ink::test]
+        fn token_royalty_override_works() {
+            let accounts = default_accounts();
+            let mut rmrk = Rmrk::new();
+            assert_eq!(rmrk.set_royalty(accounts.bob, 500), Ok(()));
+            assert_eq!(
+                rmrk.set_token_royalty(Id::U64(2), accounts.charlie, 1_000),
+                Ok(())
+            );
+            assert_eq!(
+                rmrk.royalty_info(Id::U64(2), SALE_PRICE),
+                (accounts.charlie, 1_000)
+            );
+            assert_eq!(
+                rmrk.royalty_info(Id::U64(1), SALE_PRICE),
+                (accounts.bob, 500)
+            );
+            assert_eq!(
+                rmrk.set_token_royalty(Id::U64(2), accounts.charlie, ROYALTY_DENOMINATOR + 1),
+                Err(RmrkError::RoyaltyTooHigh.into())
+            );
+
+            assert_eq!(rmrk.reset_token_royalty(Id::U64(2)), Ok(()));
+            assert_eq!(
+                rmrk.royalty_info(Id::U64(2), SALE_PRICE),
+                (accounts.bob, 500)
+            );
+        }
+
+        #[ink::test]
+        fn royalty_amount_does_not_overflow() {
+            assert_eq!(
+                royalty_amount(Balance::MAX, ROYALTY_DENOMINATOR),
+                Balance::MAX
+            );
+            assert_eq!(royalty_amount(Balance::MAX, 0), 0);
+            assert_eq!(royalty_amount(19_999, 5_000), 9_999);
+        }
+    }
+}
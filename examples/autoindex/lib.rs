@@ -29,6 +29,7 @@ pub mod rmrk_example_equippable {
             MintingAutoIndexData,
             MultiAssetAutoIndexData,
         },
+        minting::*,
         query::*,
         storage::*,
         traits::*,
@@ -69,12 +70,29 @@ pub mod rmrk_example_equippable {
 
     impl MintingAutoIndex for Rmrk {}
 
+    impl MintingAutoIndexWithAssets for Rmrk {}
+
     impl MultiAsset for Rmrk {}
 
     impl MultiAssetAutoIndex for Rmrk {}
 
     impl Query for Rmrk {}
 
+    impl psp34::Internal for Rmrk {
+        /// Reset the asset approvals of transferred tokens.
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            id: &Id,
+        ) -> core::result::Result<(), PSP34Error> {
+            if from.is_some() {
+                self.reset_approval_for_assets(id);
+            }
+            Ok(())
+        }
+    }
+
     impl Rmrk {
         /// Instantiate new RMRK contract
         #[ink(constructor)]
@@ -116,4 +134,78 @@ pub mod rmrk_example_equippable {
             Ok(())
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{
+            Environment,
+            Rmrk,
+        };
+
+        use openbrush::{
+            contracts::psp34::extensions::enumerable::*,
+            traits::String,
+        };
+
+        use ink::env::test;
+
+        use rmrk::{
+            errors::RmrkError,
+            minting::MintingAutoIndexWithAssets,
+            traits::{
+                MultiAsset,
+                MultiAssetAutoIndex,
+            },
+        };
+
+        fn init() -> Rmrk {
+            Rmrk::new(
+                String::from("Rmrk Project"),
+                String::from("RMK"),
+                String::from("ipfs://myIpfsUri/"),
+                Some(10),
+                String::from("ipfs://myIpfsUri/"),
+            )
+        }
+
+        #[ink::test]
+        fn mint_next_with_assets_works() {
+            let accounts = test::default_accounts::<Environment>();
+            let mut rmrk = init();
+            assert_eq!(
+                MultiAssetAutoIndex::add_asset_entry(
+                    &mut rmrk,
+                    None,
+                    0,
+                    String::from("asset_uri"),
+                    vec![]
+                ),
+                Ok(1)
+            );
+            assert_eq!(
+                MultiAssetAutoIndex::add_asset_entry(
+                    &mut rmrk,
+                    None,
+                    0,
+                    String::from("asset_uri"),
+                    vec![]
+                ),
+                Ok(2)
+            );
+
+            assert_eq!(
+                rmrk.mint_next_with_assets(accounts.bob, vec![1, 3]),
+                Err(RmrkError::AssetIdNotFound.into())
+            );
+            assert_eq!(rmrk.total_supply(), 0);
+
+            assert_eq!(
+                rmrk.mint_next_with_assets(accounts.bob, vec![1, 2]),
+                Ok(Id::U64(1))
+            );
+            assert_eq!(rmrk.owner_of(Id::U64(1)), Some(accounts.bob));
+            assert_eq!(rmrk.get_accepted_token_assets(Id::U64(1)), Ok(vec![1, 2]));
+            assert_eq!(rmrk.get_pending_token_assets(Id::U64(1)), Ok(vec![]));
+        }
+    }
 }
@@ -26,12 +26,17 @@ pub mod rmrk_example_equippable {
     };
 
     use rmrk::{
+        burn::*,
         config,
+        errors::Error,
         extensions::*,
         query::*,
+        soulbound::*,
+        splitter::*,
         storage::*,
         traits::*,
         types::*,
+        utils::OwnershipHooks,
     };
 
     /// Event emitted when a token transfer occurs.
@@ -101,6 +106,17 @@ pub mod rmrk_example_equippable {
         child_token_id: Id,
     }
 
+    /// Event emitted when a pending child is reclaimed by its sender.
+    #[ink(event)]
+    pub struct ChildReclaimed {
+        #[ink(topic)]
+        parent: Id,
+        #[ink(topic)]
+        child_collection: AccountId,
+        #[ink(topic)]
+        child_token_id: Id,
+    }
+
     /// Event emitted when new asset is set for the collection.
     #[ink(event)]
     pub struct AssetSet {
@@ -154,6 +170,15 @@ pub mod rmrk_example_equippable {
         priorities: Vec<AssetId>,
     }
 
+    /// Event emitted when the account approved to manage the token's assets changes.
+    #[ink(event)]
+    pub struct ApprovalForAssets {
+        #[ink(topic)]
+        token: Id,
+        #[ink(topic)]
+        operator: Option<AccountId>,
+    }
+
     /// Event emitted when the asset is equipped.
     #[ink(event)]
     pub struct AssetEquipped {
@@ -190,6 +215,39 @@ pub mod rmrk_example_equippable {
         parent: AccountId,
     }
 
+    /// Event emitted when a payee is added to the payment splitter.
+    #[ink(event)]
+    pub struct PayeeAdded {
+        #[ink(topic)]
+        account: AccountId,
+        shares: u128,
+    }
+
+    /// Event emitted when a payment is released to a payee.
+    #[ink(event)]
+    pub struct PaymentReleased {
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    /// Event emitted when a PSP22 payment is released to a payee.
+    #[ink(event)]
+    pub struct Psp22PaymentReleased {
+        #[ink(topic)]
+        token: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    /// Event emitted when a token, or the whole collection if `token_id` is `None`, is locked.
+    #[ink(event)]
+    pub struct Locked {
+        #[ink(topic)]
+        token_id: Option<Id>,
+    }
+
     // Rmrk contract storage
     #[ink(storage)]
     #[derive(Default, Storage)]
@@ -212,6 +270,12 @@ pub mod rmrk_example_equippable {
         minting_autoindex: MintingAutoIndexData,
         #[storage_field]
         equippable: EquippableData,
+        #[storage_field]
+        royalty: RoyaltyData,
+        #[storage_field]
+        splitter: PaymentSplitterData,
+        #[storage_field]
+        soulbound: SoulboundData,
     }
 
     impl PSP34 for Rmrk {}
@@ -226,12 +290,41 @@ pub mod rmrk_example_equippable {
 
     impl Nesting for Rmrk {}
 
+    impl NestingLimits for Rmrk {}
+
+    impl NestingMigration for Rmrk {}
+
+    impl NestingHooks for Rmrk {}
+
+    impl Ownership for Rmrk {}
+
+    impl ChildCollections for Rmrk {}
+
     impl MultiAsset for Rmrk {}
 
     impl Equippable for Rmrk {}
 
     impl Query for Rmrk {}
 
+    impl Burnable for Rmrk {}
+
+    impl Royalty for Rmrk {}
+
+    impl PaymentSplitter for Rmrk {}
+
+    impl RevenueHooks for Rmrk {
+        /// Record the mint payments for the payees
+        fn _on_revenue_received(
+            &mut self,
+            token: Option<AccountId>,
+            amount: Balance,
+        ) -> Result<(), Error> {
+            self.record_revenue(token, amount)
+        }
+    }
+
+    impl Soulbound for Rmrk {}
+
     impl Rmrk {
         /// Instantiate new RMRK contract
         #[allow(clippy::too_many_arguments)]
@@ -243,9 +336,9 @@ pub mod rmrk_example_equippable {
             max_supply: Option<u64>,
             price_per_mint: Balance,
             collection_metadata: String,
-            _royalty_receiver: AccountId,
-            _royalty: u8,
-        ) -> Self {
+            royalty_receiver: AccountId,
+            royalty_bps: u16,
+        ) -> Result<Self, Error> {
             let mut instance = Rmrk::default();
             config::with_admin(&mut instance, Self::env().caller());
             config::with_lazy_mint(&mut instance, price_per_mint);
@@ -257,11 +350,39 @@ pub mod rmrk_example_equippable {
                 collection_metadata,
                 max_supply,
             );
-            instance
+            config::with_royalties(&mut instance, royalty_receiver, royalty_bps)?;
+            Ok(instance)
         }
     }
 
     impl psp34::Internal for Rmrk {
+        /// Block transfers of soulbound tokens, and update children and asset approvals
+        /// of transferred tokens.
+        /// Soulbound tokens can still move in and out of parents of the same root owner,
+        /// in this collection or in approved child collections.
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            id: &Id,
+        ) -> Result<(), PSP34Error> {
+            if let (Err(err), Some(to)) = (self.ensure_transferable(from, to, id), to) {
+                self.ensure_root_owner_kept(to, id).map_err(|_| err)?;
+            }
+            match (from, to) {
+                (_, None) => {
+                    self.soulbound.locked_tokens.remove(id);
+                    self.reset_approval_for_assets(id);
+                }
+                (Some(_), Some(_)) => {
+                    self.on_parent_transfer(id)?;
+                    self.reset_approval_for_assets(id);
+                }
+                _ => (),
+            }
+            Ok(())
+        }
+
         /// Emit Transfer event
         fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
             self.env().emit_event(Transfer { from, to, id });
@@ -330,6 +451,20 @@ pub mod rmrk_example_equippable {
                 child_token_id: child_token_id.clone(),
             });
         }
+
+        /// Emit ChildReclaimed event
+        fn _emit_child_reclaimed_event(
+            &self,
+            parent: &Id,
+            child_collection: &AccountId,
+            child_token_id: &Id,
+        ) {
+            self.env().emit_event(ChildReclaimed {
+                parent: parent.clone(),
+                child_collection: *child_collection,
+                child_token_id: child_token_id.clone(),
+            });
+        }
     }
 
     impl MultiAssetEvents for Rmrk {
@@ -383,6 +518,14 @@ pub mod rmrk_example_equippable {
                 priorities,
             });
         }
+
+        /// Used to notify listeners that the account approved to manage the token's assets changed.
+        fn _emit_approval_for_assets_event(&self, token_id: &Id, operator: &Option<AccountId>) {
+            self.env().emit_event(ApprovalForAssets {
+                token: token_id.clone(),
+                operator: *operator,
+            });
+        }
     }
 
     impl EquippableEvents for Rmrk {
@@ -432,4 +575,41 @@ pub mod rmrk_example_equippable {
             });
         }
     }
+
+    impl PaymentSplitterEvents for Rmrk {
+        /// Emit PayeeAdded event
+        fn _emit_payee_added_event(&self, account: AccountId, shares: u128) {
+            self.env().emit_event(PayeeAdded { account, shares });
+        }
+
+        /// Emit PaymentReleased event
+        fn _emit_payment_released_event(&self, to: AccountId, amount: Balance) {
+            self.env().emit_event(PaymentReleased { to, amount });
+        }
+
+        /// Emit Psp22PaymentReleased event
+        fn _emit_psp22_payment_released_event(
+            &self,
+            token: AccountId,
+            to: AccountId,
+            amount: Balance,
+        ) {
+            self.env()
+                .emit_event(Psp22PaymentReleased { token, to, amount });
+        }
+    }
+
+    impl SoulboundEvents for Rmrk {
+        /// Emit Locked event
+        fn _emit_locked_event(&self, token_id: Option<Id>) {
+            self.env().emit_event(Locked { token_id });
+        }
+    }
+
+    impl OwnershipHooks for Rmrk {
+        /// Let the root owner manage nested tokens
+        fn _ensure_root_owner(&self, account: AccountId, token_id: &Id) -> Result<(), Error> {
+            Ownership::ensure_root_owner(self, account, token_id)
+        }
+    }
 }
@@ -27,11 +27,16 @@ pub mod rmrk_example_equippable {
 
     use rmrk::{
         batch::*,
+        burn::*,
         config,
+        errors::Error,
+        minting::*,
         query::*,
+        soulbound::*,
         storage::*,
         traits::*,
         types::*,
+        utils::OwnershipHooks,
     };
 
     /// Event emitted when a token transfer occurs.
@@ -101,6 +106,17 @@ pub mod rmrk_example_equippable {
         child_token_id: Id,
     }
 
+    /// Event emitted when a pending child is reclaimed by its sender.
+    #[ink(event)]
+    pub struct ChildReclaimed {
+        #[ink(topic)]
+        parent: Id,
+        #[ink(topic)]
+        child_collection: AccountId,
+        #[ink(topic)]
+        child_token_id: Id,
+    }
+
     /// Event emitted when new asset is set for the collection.
     #[ink(event)]
     pub struct AssetSet {
@@ -154,6 +170,15 @@ pub mod rmrk_example_equippable {
         priorities: Vec<AssetId>,
     }
 
+    /// Event emitted when the account approved to manage the token's assets changes.
+    #[ink(event)]
+    pub struct ApprovalForAssets {
+        #[ink(topic)]
+        token: Id,
+        #[ink(topic)]
+        operator: Option<AccountId>,
+    }
+
     /// Event emitted when the asset is equipped.
     #[ink(event)]
     pub struct AssetEquipped {
@@ -190,6 +215,13 @@ pub mod rmrk_example_equippable {
         parent: AccountId,
     }
 
+    /// Event emitted when a token, or the whole collection if `token_id` is `None`, is locked.
+    #[ink(event)]
+    pub struct Locked {
+        #[ink(topic)]
+        token_id: Option<Id>,
+    }
+
     // Rmrk contract storage
     #[ink(storage)]
     #[derive(Default, Storage)]
@@ -210,6 +242,8 @@ pub mod rmrk_example_equippable {
         minting: MintingData,
         #[storage_field]
         equippable: EquippableData,
+        #[storage_field]
+        soulbound: SoulboundData,
     }
 
     impl PSP34 for Rmrk {}
@@ -224,14 +258,32 @@ pub mod rmrk_example_equippable {
 
     impl Nesting for Rmrk {}
 
+    impl NestingLimits for Rmrk {}
+
+    impl NestingMigration for Rmrk {}
+
+    impl NestingHooks for Rmrk {}
+
+    impl Ownership for Rmrk {}
+
+    impl ChildCollections for Rmrk {}
+
     impl MultiAsset for Rmrk {}
 
     impl Equippable for Rmrk {}
 
     impl Query for Rmrk {}
 
+    impl Burnable for Rmrk {}
+
     impl BatchCalls for Rmrk {}
 
+    impl MintingWithAssets for Rmrk {}
+
+    impl NestMinting for Rmrk {}
+
+    impl Soulbound for Rmrk {}
+
     impl Rmrk {
         /// Instantiate new RMRK contract
         #[allow(clippy::too_many_arguments)]
@@ -258,6 +310,33 @@ pub mod rmrk_example_equippable {
     }
 
     impl psp34::Internal for Rmrk {
+        /// Block transfers of soulbound tokens, and update children and asset approvals
+        /// of transferred tokens.
+        /// Soulbound tokens can still move in and out of parents of the same root owner,
+        /// in this collection or in approved child collections.
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            id: &Id,
+        ) -> Result<(), PSP34Error> {
+            if let (Err(err), Some(to)) = (self.ensure_transferable(from, to, id), to) {
+                self.ensure_root_owner_kept(to, id).map_err(|_| err)?;
+            }
+            match (from, to) {
+                (_, None) => {
+                    self.soulbound.locked_tokens.remove(id);
+                    self.reset_approval_for_assets(id);
+                }
+                (Some(_), Some(_)) => {
+                    self.on_parent_transfer(id)?;
+                    self.reset_approval_for_assets(id);
+                }
+                _ => (),
+            }
+            Ok(())
+        }
+
         /// Emit Transfer event
         fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
             self.env().emit_event(Transfer { from, to, id });
@@ -326,6 +405,20 @@ pub mod rmrk_example_equippable {
                 child_token_id: child_token_id.clone(),
             });
         }
+
+        /// Emit ChildReclaimed event
+        fn _emit_child_reclaimed_event(
+            &self,
+            parent: &Id,
+            child_collection: &AccountId,
+            child_token_id: &Id,
+        ) {
+            self.env().emit_event(ChildReclaimed {
+                parent: parent.clone(),
+                child_collection: *child_collection,
+                child_token_id: child_token_id.clone(),
+            });
+        }
     }
 
     impl MultiAssetEvents for Rmrk {
@@ -379,6 +472,14 @@ pub mod rmrk_example_equippable {
                 priorities,
             });
         }
+
+        /// Used to notify listeners that the account approved to manage the token's assets changed.
+        fn _emit_approval_for_assets_event(&self, token_id: &Id, operator: &Option<AccountId>) {
+            self.env().emit_event(ApprovalForAssets {
+                token: token_id.clone(),
+                operator: *operator,
+            });
+        }
     }
 
     impl EquippableEvents for Rmrk {
@@ -429,6 +530,20 @@ pub mod rmrk_example_equippable {
         }
     }
 
+    impl SoulboundEvents for Rmrk {
+        /// Emit Locked event
+        fn _emit_locked_event(&self, token_id: Option<Id>) {
+            self.env().emit_event(Locked { token_id });
+        }
+    }
+
+    impl OwnershipHooks for Rmrk {
+        /// Let the root owner manage nested tokens
+        fn _ensure_root_owner(&self, account: AccountId, token_id: &Id) -> Result<(), Error> {
+            Ownership::ensure_root_owner(self, account, token_id)
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::{
@@ -456,8 +571,27 @@ pub mod rmrk_example_equippable {
         use ink::env::test;
 
         use rmrk::{
+            burn::Burnable,
+            errors::RmrkError,
+            minting::{
+                MintingWithAssets,
+                NestMinting,
+            },
             roles::ADMIN,
-            traits::Minting,
+            soulbound::Soulbound,
+            storage::MAX_NESTING_DEPTH,
+            traits::{
+                ChildCollections,
+                Equippable,
+                Minting,
+                MultiAsset,
+                Nesting,
+                NestingHooks,
+                NestingLimits,
+                NestingMigration,
+                Ownership,
+            },
+            types::Equipment,
             utils::Utils,
         };
 
@@ -520,6 +654,576 @@ pub mod rmrk_example_equippable {
             );
         }
 
+        #[ink::test]
+        fn burn_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
+            assert!(rmrk
+                .add_asset_entry(None, 1, 0, String::from("asset_uri"), vec![])
+                .is_ok());
+            assert!(rmrk.add_asset_to_token(Id::U64(1), 1, None).is_ok());
+
+            // Only token owner can burn
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.burn(Id::U64(1), 0),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.burn(Id::U64(1), 0), Ok(0));
+            assert_eq!(rmrk.total_supply(), 0);
+            assert_eq!(rmrk.owner_of(Id::U64(1)), None);
+            assert_eq!(rmrk.multiasset.accepted_assets.get(&Id::U64(1)), None);
+        }
+
+        #[ink::test]
+        fn burn_unequips_slots() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
+            assert!(rmrk
+                .add_asset_entry(None, 1, 0, String::from("asset_uri"), vec![1, 2])
+                .is_ok());
+            assert!(rmrk.add_asset_to_token(Id::U64(1), 1, None).is_ok());
+            let equipment = Equipment {
+                asset_id: 1,
+                child_asset_id: 5,
+                child_nft: (accounts.charlie, Id::U64(7)),
+            };
+            rmrk.equippable
+                .equipment
+                .insert((Id::U64(1), 1), &equipment);
+            assert_eq!(rmrk.get_equipment(Id::U64(1), 1), Some(equipment));
+
+            // Slots of the burned token are unequipped, without children there is nothing to burn
+            assert_eq!(rmrk.burn(Id::U64(1), 0), Ok(0));
+            assert_eq!(rmrk.get_equipment(Id::U64(1), 1), None);
+            assert_eq!(rmrk.get_equipment(Id::U64(1), 2), None);
+            assert_eq!(rmrk.multiasset.pending_assets.get(&Id::U64(1)), None);
+        }
+
+        #[ink::test]
+        fn mint_with_assets_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk
+                .add_asset_entry(None, 1, 0, String::from("asset_uri"), vec![])
+                .is_ok());
+            assert!(rmrk
+                .add_asset_entry(None, 2, 0, String::from("asset_uri"), vec![])
+                .is_ok());
+
+            assert_eq!(
+                rmrk.mint_with_assets(accounts.bob, Id::U64(1), vec![1, 3]),
+                Err(RmrkError::AssetIdNotFound.into())
+            );
+            assert_eq!(
+                rmrk.mint_with_assets(accounts.bob, Id::U64(1), vec![1, 1]),
+                Err(RmrkError::AlreadyAddedAsset.into())
+            );
+            assert_eq!(rmrk.total_supply(), 0);
+
+            assert!(rmrk
+                .mint_with_assets(accounts.bob, Id::U64(1), vec![1, 2])
+                .is_ok());
+            assert_eq!(rmrk.owner_of(Id::U64(1)), Some(accounts.bob));
+            assert_eq!(rmrk.get_accepted_token_assets(Id::U64(1)), Ok(vec![1, 2]));
+            assert_eq!(rmrk.get_pending_token_assets(Id::U64(1)), Ok(vec![]));
+        }
+
+        #[ink::test]
+        fn nest_mint_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
+            assert!(rmrk.mint(accounts.bob, Id::U64(2)).is_ok());
+
+            // Only contributor can mint
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.nest_mint(collection, Id::U64(2), Id::U64(3)),
+                Err(MissingRole.into())
+            );
+
+            // Child is accepted if caller owns the parent
+            set_sender(accounts.alice);
+            assert!(rmrk.nest_mint(collection, Id::U64(1), Id::U64(3)).is_ok());
+            assert_eq!(rmrk.owner_of(Id::U64(3)), Some(collection));
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(1), 0, 10),
+                vec![(collection, Id::U64(3))]
+            );
+            assert_eq!(
+                rmrk.get_parent_of_child((collection, Id::U64(3))),
+                Some(Id::U64(1))
+            );
+            assert_eq!(
+                rmrk.direct_owner(Id::U64(3)),
+                Ok((collection, Some(Id::U64(1)), true))
+            );
+
+            // Child is pending otherwise
+            assert!(rmrk.nest_mint(collection, Id::U64(2), Id::U64(4)).is_ok());
+            assert_eq!(
+                rmrk.get_pending_children(Id::U64(2), 0, 10),
+                vec![(collection, Id::U64(4))]
+            );
+
+            assert_eq!(
+                rmrk.nest_mint(collection, Id::U64(5), Id::U64(6)),
+                Err(PSP34Error::TokenNotExists.into())
+            );
+
+            // Child can't be nested into itself
+            assert_eq!(
+                rmrk.transfer_child(Id::U64(1), Id::U64(3), (collection, Id::U64(3))),
+                Err(RmrkError::NestingCycle.into())
+            );
+        }
+
+        #[ink::test]
+        fn child_collections_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            let child_collection = accounts.charlie;
+            assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
+            assert!(rmrk.allow_any_child_collection());
+
+            // Only admin can manage child collections
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_allow_any_child_collection(false),
+                Err(MissingRole.into())
+            );
+            assert_eq!(
+                rmrk.approve_child_collection(child_collection, None),
+                Err(MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert!(rmrk.set_allow_any_child_collection(false).is_ok());
+            assert_eq!(rmrk.child_collection(child_collection), (false, None));
+            assert_eq!(
+                rmrk.add_child(Id::U64(1), (child_collection, Id::U64(1))),
+                Err(RmrkError::ChildContractNotApproved.into())
+            );
+
+            // Approved collection is limited per parent token
+            assert!(rmrk
+                .approve_child_collection(child_collection, Some(0))
+                .is_ok());
+            assert_eq!(rmrk.child_collection(child_collection), (true, Some(0)));
+            assert_eq!(
+                rmrk.add_child(Id::U64(1), (child_collection, Id::U64(1))),
+                Err(RmrkError::MaxCollectionChildrenReached.into())
+            );
+            assert!(rmrk.revoke_child_collection(child_collection).is_ok());
+            assert_eq!(rmrk.child_collection(child_collection), (false, None));
+
+            // Children from this collection are always allowed
+            assert!(rmrk.nest_mint(collection, Id::U64(1), Id::U64(2)).is_ok());
+        }
+
+        #[ink::test]
+        fn nesting_children_limits_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
+            assert!(rmrk.mint(accounts.bob, Id::U64(2)).is_ok());
+
+            // Only admin can set limits
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.set_nesting_limits(Some(1), Some(1), None),
+                Err(MissingRole.into())
+            );
+            set_sender(accounts.alice);
+            assert_eq!(
+                rmrk.set_nesting_limits(None, None, Some(MAX_NESTING_DEPTH + 1)),
+                Err(RmrkError::BadConfig.into())
+            );
+            assert!(rmrk.set_nesting_limits(Some(1), Some(1), None).is_ok());
+            assert_eq!(rmrk.nesting_limits(), (Some(1), Some(1), None));
+
+            assert!(rmrk.nest_mint(collection, Id::U64(1), Id::U64(3)).is_ok());
+            assert_eq!(
+                rmrk.nest_mint(collection, Id::U64(1), Id::U64(4)),
+                Err(RmrkError::MaxAcceptedChildrenReached.into())
+            );
+            assert!(rmrk.nest_mint(collection, Id::U64(2), Id::U64(5)).is_ok());
+            assert_eq!(
+                rmrk.nest_mint(collection, Id::U64(2), Id::U64(6)),
+                Err(RmrkError::MaxPendingChildrenReached.into())
+            );
+
+            // Pending child can't be accepted over the limit
+            assert!(rmrk.set_nesting_limits(Some(0), None, None).is_ok());
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.accept_child(Id::U64(2), (collection, Id::U64(5))),
+                Err(RmrkError::MaxAcceptedChildrenReached.into())
+            );
+        }
+
+        #[ink::test]
+        fn nesting_depth_counts_children_of_the_child() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
+            assert!(rmrk.mint(accounts.alice, Id::U64(2)).is_ok());
+            assert!(rmrk.set_nesting_limits(None, None, Some(2)).is_ok());
+
+            // Token 1 holds child 3, which holds child 4. Token 2 holds child 5
+            assert!(rmrk.nest_mint(collection, Id::U64(1), Id::U64(3)).is_ok());
+            assert!(rmrk.nest_mint(collection, Id::U64(3), Id::U64(4)).is_ok());
+            assert!(rmrk.nest_mint(collection, Id::U64(2), Id::U64(5)).is_ok());
+            assert_eq!(rmrk.children_depth(Id::U64(1), MAX_NESTING_DEPTH), 2);
+            assert_eq!(rmrk.children_depth(Id::U64(1), 1), 1);
+            assert_eq!(rmrk.children_depth(Id::U64(4), MAX_NESTING_DEPTH), 0);
+
+            // Child 4 would end up three levels deep under token 5
+            assert_eq!(
+                rmrk.transfer_child(Id::U64(1), Id::U64(5), (collection, Id::U64(3))),
+                Err(RmrkError::MaxNestingDepthReached.into())
+            );
+            assert!(rmrk
+                .transfer_child(Id::U64(1), Id::U64(2), (collection, Id::U64(3)))
+                .is_ok());
+            assert_eq!(rmrk.root_owner(Id::U64(4)), Ok(accounts.alice));
+        }
+
+        #[ink::test]
+        fn children_pagination_and_migration_work() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
+
+            // Children stored in the legacy layout are read until migrated
+            let legacy_children: Vec<ChildNft> =
+                (2..6).map(|id| (collection, Id::U64(id))).collect();
+            rmrk.nesting
+                .accepted_children
+                .insert(Id::U64(1), &legacy_children);
+            assert_eq!(rmrk.children_balance(Id::U64(1)), Ok((4, 0)));
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(1), 1, 2),
+                legacy_children[1..3].to_vec()
+            );
+
+            // Only admin can migrate
+            set_sender(accounts.bob);
+            assert_eq!(rmrk.migrate_children(0, 10), Err(MissingRole.into()));
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.migrate_children(0, 10), Ok(4));
+            assert_eq!(rmrk.migrate_children(0, 10), Ok(0));
+            assert_eq!(rmrk.nesting.accepted_children.get(Id::U64(1)), None);
+            assert_eq!(rmrk.children_balance(Id::U64(1)), Ok((4, 0)));
+
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(1), 0, 10),
+                legacy_children
+            );
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(1), 1, 2),
+                legacy_children[1..3].to_vec()
+            );
+            assert_eq!(rmrk.get_accepted_children(Id::U64(1), 4, 2), vec![]);
+
+            // Last child takes the place of the removed one
+            assert!(rmrk.mint(accounts.alice, Id::U64(6)).is_ok());
+            assert!(rmrk
+                .transfer_child(Id::U64(1), Id::U64(6), (collection, Id::U64(3)))
+                .is_ok());
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(1), 0, 10),
+                vec![
+                    (collection, Id::U64(2)),
+                    (collection, Id::U64(5)),
+                    (collection, Id::U64(4)),
+                ]
+            );
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(6), 0, 10),
+                vec![(collection, Id::U64(3))]
+            );
+
+            // First change of the children migrates the parent
+            assert!(rmrk.mint(accounts.alice, Id::U64(7)).is_ok());
+            rmrk.nesting.accepted_children.insert(
+                Id::U64(7),
+                &vec![(collection, Id::U64(8)), (collection, Id::U64(9))],
+            );
+            assert!(rmrk
+                .transfer_child(Id::U64(7), Id::U64(6), (collection, Id::U64(8)))
+                .is_ok());
+            assert_eq!(rmrk.nesting.accepted_children.get(Id::U64(7)), None);
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(7), 0, 10),
+                vec![(collection, Id::U64(9))]
+            );
+        }
+
+        #[ink::test]
+        fn soulbound_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+
+            // Only admin can lock tokens, and only before they are minted
+            set_sender(accounts.bob);
+            assert_eq!(rmrk.lock_token(Id::U64(1)), Err(MissingRole.into()));
+            set_sender(accounts.alice);
+            assert!(rmrk.lock_token(Id::U64(1)).is_ok());
+            assert!(rmrk.mint(accounts.alice, Id::U64(1)).is_ok());
+            assert!(rmrk.mint(accounts.alice, Id::U64(2)).is_ok());
+            assert_eq!(
+                rmrk.lock_token(Id::U64(2)),
+                Err(PSP34Error::TokenExists.into())
+            );
+            assert!(rmrk.approve(accounts.bob, None, true).is_ok());
+            assert_eq!(rmrk.locked(Id::U64(1)), Ok(true));
+            assert_eq!(rmrk.locked(Id::U64(2)), Ok(false));
+
+            // Locked token can not change hands, unlocked one can
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.transfer(accounts.charlie, Id::U64(1), vec![]),
+                Err(PSP34Error::Custom(String::from("CannotTransferSoulbound")))
+            );
+            assert!(rmrk.transfer(accounts.charlie, Id::U64(2), vec![]).is_ok());
+
+            // Locked token can be burned
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.burn(Id::U64(1), 0), Ok(0));
+            assert_eq!(rmrk.soulbound.locked_tokens.get(Id::U64(1)), None);
+
+            // Locking the collection locks every token
+            assert!(rmrk.lock_collection().is_ok());
+            assert_eq!(rmrk.locked(Id::U64(2)), Ok(true));
+        }
+
+        #[ink::test]
+        fn parent_transfer_works() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.bob, Id::U64(1)).is_ok());
+
+            // Token 1 holds accepted child 3, which holds accepted child 5,
+            // which holds pending child 6
+            assert!(rmrk.nest_mint(collection, Id::U64(1), Id::U64(3)).is_ok());
+            assert!(rmrk.nest_mint(collection, Id::U64(3), Id::U64(5)).is_ok());
+            set_sender(accounts.bob);
+            assert!(rmrk
+                .accept_child(Id::U64(1), (collection, Id::U64(3)))
+                .is_ok());
+            assert!(rmrk
+                .accept_child(Id::U64(3), (collection, Id::U64(5)))
+                .is_ok());
+            set_sender(accounts.alice);
+            assert!(rmrk.nest_mint(collection, Id::U64(5), Id::U64(6)).is_ok());
+            assert_eq!(rmrk.root_owner(Id::U64(5)), Ok(accounts.bob));
+
+            // Accepted subtree follows the token, pending children of nested tokens stay
+            set_sender(accounts.bob);
+            assert!(rmrk.transfer(accounts.charlie, Id::U64(1), vec![]).is_ok());
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(1), 0, 10),
+                vec![(collection, Id::U64(3))]
+            );
+            assert_eq!(
+                rmrk.direct_owner(Id::U64(5)),
+                Ok((collection, Some(Id::U64(3)), true))
+            );
+            assert_eq!(rmrk.root_owner(Id::U64(3)), Ok(accounts.charlie));
+            assert_eq!(rmrk.root_owner(Id::U64(5)), Ok(accounts.charlie));
+            assert_eq!(
+                rmrk.get_pending_children(Id::U64(5), 0, 10),
+                vec![(collection, Id::U64(6))]
+            );
+
+            // New root owner manages the subtree, the previous one no longer does
+            assert_eq!(
+                rmrk.accept_child(Id::U64(5), (collection, Id::U64(6))),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+            set_sender(accounts.charlie);
+            assert!(rmrk
+                .accept_child(Id::U64(5), (collection, Id::U64(6)))
+                .is_ok());
+            assert_eq!(rmrk.root_owner(Id::U64(6)), Ok(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn asset_approval_is_reset_on_transfer() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            assert!(rmrk
+                .add_asset_entry(None, 1, 0, String::from("asset_uri"), vec![])
+                .is_ok());
+            assert!(rmrk
+                .add_asset_entry(None, 2, 0, String::from("asset_uri"), vec![])
+                .is_ok());
+            assert!(rmrk.mint(accounts.bob, Id::U64(1)).is_ok());
+            assert!(rmrk.add_asset_to_token(Id::U64(1), 1, None).is_ok());
+            assert!(rmrk.add_asset_to_token(Id::U64(1), 2, None).is_ok());
+
+            // Only the owner can approve an account for the token's assets
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.accept_asset(Id::U64(1), 1),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+            assert_eq!(
+                rmrk.approve_for_assets(Some(accounts.charlie), Id::U64(1)),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+            set_sender(accounts.bob);
+            assert!(rmrk
+                .approve_for_assets(Some(accounts.charlie), Id::U64(1))
+                .is_ok());
+            assert_eq!(
+                rmrk.get_approved_for_assets(Id::U64(1)),
+                Some(accounts.charlie)
+            );
+            set_sender(accounts.charlie);
+            assert!(rmrk.accept_asset(Id::U64(1), 1).is_ok());
+            assert_eq!(rmrk.get_accepted_token_assets(Id::U64(1)), Ok(vec![1]));
+
+            // Approval doesn't follow the token to its new owner
+            set_sender(accounts.bob);
+            assert!(rmrk.transfer(accounts.eve, Id::U64(1), vec![]).is_ok());
+            assert_eq!(rmrk.get_approved_for_assets(Id::U64(1)), None);
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.reject_asset(Id::U64(1), 2),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+            set_sender(accounts.eve);
+            assert!(rmrk.reject_asset(Id::U64(1), 2).is_ok());
+        }
+
+        #[ink::test]
+        fn reclaim_child_requires_sender_and_timeout() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.bob, Id::U64(1)).is_ok());
+            assert!(rmrk.nest_mint(collection, Id::U64(1), Id::U64(2)).is_ok());
+            let child = (collection, Id::U64(2));
+
+            // Only the sender can reclaim
+            set_sender(accounts.bob);
+            assert_eq!(
+                rmrk.reclaim_child(Id::U64(1), child.clone()),
+                Err(RmrkError::NotChildSender.into())
+            );
+
+            // No reclaim without a timeout
+            set_sender(accounts.alice);
+            assert_eq!(rmrk.pending_child_timeout(), None);
+            assert_eq!(
+                rmrk.reclaim_child(Id::U64(1), child.clone()),
+                Err(RmrkError::PendingChildNotExpired.into())
+            );
+
+            // No reclaim before the timeout elapsed
+            assert!(rmrk.set_pending_child_timeout(Some(1_000)).is_ok());
+            assert_eq!(rmrk.pending_child_timeout(), Some(1_000));
+            assert_eq!(
+                rmrk.reclaim_child(Id::U64(1), child.clone()),
+                Err(RmrkError::PendingChildNotExpired.into())
+            );
+            assert_eq!(rmrk.get_pending_children(Id::U64(1), 0, 10), vec![child]);
+
+            // Only admin can set the timeout
+            set_sender(accounts.bob);
+            assert!(rmrk.set_pending_child_timeout(Some(0)).is_err());
+        }
+
+        #[ink::test]
+        fn nesting_hooks_consent_by_default() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.bob, Id::U64(1)).is_ok());
+            assert!(rmrk.nest_mint(collection, Id::U64(1), Id::U64(2)).is_ok());
+            assert_eq!(rmrk.on_nested(Id::U64(1), Id::U64(2)), Ok(()));
+
+            // Hooks of this collection are called locally on add and on accept
+            set_sender(accounts.bob);
+            assert!(rmrk
+                .accept_child(Id::U64(1), (collection, Id::U64(2)))
+                .is_ok());
+            assert_eq!(
+                rmrk.get_accepted_children(Id::U64(1), 0, 10),
+                vec![(collection, Id::U64(2))]
+            );
+        }
+
+        #[ink::test]
+        fn transfer_child_to_collection_checks_parent_owner() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.bob, Id::U64(1)).is_ok());
+            assert!(rmrk.mint(accounts.bob, Id::U64(2)).is_ok());
+            assert!(rmrk.nest_mint(collection, Id::U64(1), Id::U64(3)).is_ok());
+            let child = (collection, Id::U64(3));
+            set_sender(accounts.bob);
+            assert!(rmrk.accept_child(Id::U64(1), child.clone()).is_ok());
+
+            // Only the owner of the current parent can transfer the child
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.transfer_child_to_collection(
+                    Id::U64(1),
+                    accounts.django,
+                    Id::U64(1),
+                    child.clone()
+                ),
+                Err(RmrkError::NotTokenOwner.into())
+            );
+
+            // Destination in this collection moves the child between parents
+            set_sender(accounts.bob);
+            assert!(rmrk
+                .transfer_child_to_collection(Id::U64(1), collection, Id::U64(2), child.clone())
+                .is_ok());
+            assert_eq!(rmrk.get_accepted_children(Id::U64(1), 0, 10), vec![]);
+            assert_eq!(rmrk.get_accepted_children(Id::U64(2), 0, 10), vec![child]);
+        }
+
+        #[ink::test]
+        fn add_transferred_child_checks_sender() {
+            let accounts = default_accounts();
+            let mut rmrk = init();
+            let collection = test::callee::<Environment>();
+            assert!(rmrk.mint(accounts.bob, Id::U64(1)).is_ok());
+            assert!(rmrk.mint(accounts.django, Id::U64(2)).is_ok());
+            let child = (collection, Id::U64(2));
+
+            // Only the owner of the child can add it
+            set_sender(accounts.charlie);
+            assert_eq!(
+                rmrk.add_transferred_child(Id::U64(1), child.clone(), accounts.django),
+                Err(RmrkError::ChildNotOwnedByCaller.into())
+            );
+
+            // Forged sender can't land the child as accepted in bob's parent
+            set_sender(accounts.django);
+            assert_eq!(
+                rmrk.add_transferred_child(Id::U64(1), child, accounts.bob),
+                Err(RmrkError::NotChildSender.into())
+            );
+            assert_eq!(rmrk.children_balance(Id::U64(1)), Ok((0, 0)));
+        }
+
         fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
             test::default_accounts::<Environment>()
         }
@@ -0,0 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+/// Minimal PSP22 token, used as a payment token for lazy minting in tests.
+#[openbrush::contract]
+pub mod rmrk_example_psp22 {
+    use openbrush::{
+        contracts::psp22::*,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Token {
+        #[storage_field]
+        psp22: psp22::Data,
+    }
+
+    impl PSP22 for Token {}
+
+    impl Token {
+        /// Instantiate new token, minting `total_supply` to the caller.
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Result<Self, PSP22Error> {
+            let mut instance = Self::default();
+            instance._mint_to(Self::env().caller(), total_supply)?;
+            Ok(instance)
+        }
+    }
+}
@@ -2,7 +2,7 @@ import { expect, use } from "chai";
 import chaiAsPromised from "chai-as-promised";
 import Catalog_Factory from "../types/constructors/catalog_example";
 import Contract from "../types/contracts/catalog_example";
-import { RmrkError } from "../types/types-returns/catalog_example";
+import { RmrkErrorBuilder } from "../types/types-returns/catalog_example";
 
 import { ApiPromise, WsProvider, Keyring } from "@polkadot/api";
 import { KeyringPair } from "@polkadot/keyring/types";
@@ -70,7 +70,7 @@ describe("RMRK Catalog tests", () => {
     const failEnsure = await catalog
       .withSigner(deployer)
       .query.ensureEquippable(0, catalog.address)
-    expect(failEnsure.value.unwrap().err.rmrk).to.be.equal(RmrkError.addressNotEquippable);
+    expect(failEnsure.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.AddressNotEquippable());
 
     // add equippable addresses for part 0
     await catalog
@@ -83,7 +83,7 @@ describe("RMRK Catalog tests", () => {
     const failEnsure2 = await catalog
       .withSigner(deployer)
       .query.ensureEquippable(1, catalog.address)
-    expect(failEnsure2.value.unwrap().err.rmrk).to.be.equal(RmrkError.addressNotEquippable);
+    expect(failEnsure2.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.AddressNotEquippable());
 
     // remove all equippable addresses for part 0
     expect((await catalog.query.getPart(0))?.value.unwrap().equippable.toString().length).to.be.greaterThan(1);
@@ -96,7 +96,7 @@ describe("RMRK Catalog tests", () => {
     const failAddEquip = await catalog
       .withSigner(deployer)
       .query.addEquippableAddresses(1, [catalog.address]);
-    expect(failAddEquip.value.unwrap().err.rmrk).to.be.equal(RmrkError.partIsNotSlot);
+    expect(failAddEquip.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.PartIsNotSlot());
   });
 });
 
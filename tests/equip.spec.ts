@@ -12,7 +12,7 @@ import {
 
 import { ApiPromise, WsProvider, Keyring } from "@polkadot/api";
 import { KeyringPair } from "@polkadot/keyring/types";
-import { RmrkError } from "../types/types-returns/rmrk_example_equippable_lazy";
+import { RmrkErrorBuilder } from "../types/types-returns/rmrk_example_equippable_lazy";
 import { emit } from "./helper";
 
 use(chaiAsPromised);
@@ -330,40 +330,40 @@ describe("RMRK Merged Equippable", () => {
     const equipCopperError = await avatar
       .withSigner(bob)
       .query.equip({ u64: 2 }, defaultAssetId, swordSlot, [sword.address, { u64: 1 }], equippableCopperSword)
-    expect(equipCopperError.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.unknownPart
+    expect(equipCopperError.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.UnknownPart()
     );
 
     // Fails because Dave is not the token owner.
     const notOwnerError = await avatar
       .withSigner(dave)
       .query.equip({ u64: 2 }, defaultAssetId, swordSlot, [sword.address, { u64: 1 }], equippableWoodenSword)
-    expect(notOwnerError.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.notTokenOwner
+    expect(notOwnerError.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.NotTokenOwner()
     );
 
     // Fails because of non-existent sword asset. 
     const nonExistentAsset = await avatar
       .withSigner(bob)
       .query.equip({ u64: 2 }, defaultAssetId, swordSlot, [sword.address, { u64: 1 }], 7)
-    expect(nonExistentAsset.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.unknownEquippableAsset
+    expect(nonExistentAsset.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.UnknownEquippableAsset()
     );
 
     // Fails because wrong slot id.
     const wrongSlotId = await avatar
       .withSigner(bob)
       .query.equip({ u64: 2 }, defaultAssetId, 1, [sword.address, { u64: 1 }], 7)
-    expect(wrongSlotId.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.targetAssetCannotReceiveSlot
+    expect(wrongSlotId.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.TargetAssetCannotReceiveSlot()
     );
 
     // Cannot be equipped when slot isn't free.
     const slotAlreadyUsed = await avatar
       .withSigner(bob)
       .query.equip({ u64: 1 }, defaultAssetId, swordSlot, [sword.address, { u64: 1 }], 7)
-    expect(slotAlreadyUsed.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.slotAlreadyUsed
+    expect(slotAlreadyUsed.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.SlotAlreadyUsed()
     );
 
     // Now we ensure that unequip also works.
@@ -372,16 +372,16 @@ describe("RMRK Merged Equippable", () => {
     const daveCannotUnequip = await avatar
       .withSigner(dave)
       .query.unequip({ u64: 1 }, swordSlot)
-    expect(daveCannotUnequip.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.notTokenOwner
+    expect(daveCannotUnequip.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.NotTokenOwner()
     );
 
     // Cannot unequip if it is not equipped.
     const notEquipped = await avatar
       .withSigner(bob)
       .query.unequip({ u64: 2 }, swordSlot)
-    expect(notEquipped.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.notEquipped
+    expect(notEquipped.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.NotEquipped()
     );
 
     await avatar
@@ -6,7 +6,13 @@ import { ApiPromise, WsProvider, Keyring } from "@polkadot/api";
 import BN from "bn.js";
 import Rmrk_factory from "../types/constructors/rmrk_example_equippable_lazy";
 import Rmrk from "../types/contracts/rmrk_example_equippable_lazy";
-import { RmrkError } from "../types/types-returns/rmrk_example_equippable_lazy";
+import Minter_factory from "../types/constructors/rmrk_example_equippable";
+import Minter from "../types/contracts/rmrk_example_equippable";
+import {
+  CallErrorBuilder,
+  PSP34ErrorBuilder,
+  RmrkErrorBuilder,
+} from "../types/types-returns/rmrk_example_equippable_lazy";
 import { SignAndSendSuccessResponse } from "@727-ventures/typechain-types";
 
 import { emit } from "./helper";
@@ -131,7 +137,7 @@ describe("RMRK Nesting tests", () => {
     const failResult = await parent
       .withSigner(dave)
       .query.acceptChild({ u64: 1 }, [child.address, { u64: 1 }]);
-    expect(failResult.value.unwrap().err.rmrk).to.be.equal(RmrkError.notTokenOwner);
+    expect(failResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.NotTokenOwner());
 
     // bob accepts child
     await acceptChild(child, parent, bob);
@@ -140,20 +146,36 @@ describe("RMRK Nesting tests", () => {
     const failAcceptResult = await parent
       .withSigner(bob)
       .query.acceptChild({ u64: 1 }, [child.address, { u64: 1 }]);
-    expect(failAcceptResult.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.alreadyAddedChild
+    expect(failAcceptResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.AlreadyAddedChild()
     );
 
     // dave fails to remove child (not owner)
     const failRemoveChild = await parent
       .withSigner(dave)
       .query.removeChild({ u64: 1 }, [child.address, { u64: 1 }]);
-    expect(failRemoveChild.value.unwrap().err.rmrk).to.be.equal(RmrkError.notTokenOwner);
+    expect(failRemoveChild.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.NotTokenOwner());
 
     // bob removes child
     await removeChild(child, parent, bob);
   });
 
+  it("Add child without approval fails with the transfer error", async () => {
+    await mintOne(parent, bob);
+    await mintOne(child, dave);
+
+    // dave fails to add child nft, parent contract is not approved to transfer it
+    const failResult = await parent
+      .withSigner(dave)
+      .query.addChild({ u64: 1 }, [child.address, { u64: 1 }]);
+    expect(failResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.ChildTransferFailed(
+        CallErrorBuilder.Callee(PSP34ErrorBuilder.NotApproved())
+      )
+    );
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(dave.address);
+  });
+
   it("Add child (different user), reject works", async () => {
 
     // bob mints parent
@@ -175,8 +197,8 @@ describe("RMRK Nesting tests", () => {
       .withSigner(dave)
       .query.acceptChild({ u64: 1 }, [child.address, { u64: 1 }]);
 
-    expect(failAcceptResult.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.notTokenOwner
+    expect(failAcceptResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.NotTokenOwner()
     );
 
     // since bob is owner of parent, dave fails to reject child
@@ -184,8 +206,8 @@ describe("RMRK Nesting tests", () => {
       .withSigner(dave)
       .query.rejectChild({ u64: 1 }, [child.address, { u64: 1 }]);
 
-    expect(failRejectResult.value.unwrap().err.rmrk).to.be.equal(
-      RmrkError.notTokenOwner
+    expect(failRejectResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.NotTokenOwner()
     );
 
     // bob rejects child
@@ -200,6 +222,81 @@ describe("RMRK Nesting tests", () => {
     expect(
       (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
     ).to.be.equal("0,0");
+
+    // rejected child is returned to dave
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(dave.address);
+  });
+
+  it("Rejected child is returned to its owner, not to the operator adding it", async () => {
+    await mintOne(parent, bob);
+    await mintOne(child, dave);
+    await approve(child, parent, dave);
+
+    // deployer can't add dave's child nft without dave's approval
+    const failResult = await parent
+      .withSigner(deployer)
+      .query.addChild({ u64: 1 }, [child.address, { u64: 1 }]);
+    expect(failResult.value.unwrap().err.psp34).to.deep.equal(PSP34ErrorBuilder.NotApproved());
+
+    // deployer adds dave's child nft to bob's parent nft as an approved operator
+    await child.withSigner(dave).tx.approve(deployer.address, null, true);
+    await addChild(child, parent, deployer);
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(parent.address);
+
+    // bob rejects child, it goes back to dave in the child collection
+    const rejectChildResult = await parent
+      .withSigner(bob)
+      .tx.rejectChild({ u64: 1 }, [child.address, { u64: 1 }]);
+    emit(rejectChildResult, "ChildRejected", {
+      parent: { u64: 1 },
+      childCollection: child.address,
+      childTokenId: { u64: 1 },
+    });
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(dave.address);
+    expect((await child.query.balanceOf(deployer.address)).value.unwrap()).to.equal(0);
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("0,0");
+  });
+
+  it("Sender reclaims an expired pending child", async () => {
+    await mintOne(parent, bob);
+    await mintOne(child, dave);
+    await approve(child, parent, dave);
+    await addChild(child, parent, dave);
+
+    // no reclaim while the timeout is not set
+    const failReclaimResult = await parent
+      .withSigner(dave)
+      .query.reclaimChild({ u64: 1 }, [child.address, { u64: 1 }]);
+    expect(failReclaimResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.PendingChildNotExpired()
+    );
+
+    // pending children can be reclaimed right away
+    await parent.withSigner(deployer).tx.setPendingChildTimeout(0);
+
+    // bob is not the sender of the child
+    const failSenderResult = await parent
+      .withSigner(bob)
+      .query.reclaimChild({ u64: 1 }, [child.address, { u64: 1 }]);
+    expect(failSenderResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.NotChildSender()
+    );
+
+    // dave reclaims the child
+    const reclaimResult = await parent
+      .withSigner(dave)
+      .tx.reclaimChild({ u64: 1 }, [child.address, { u64: 1 }]);
+    emit(reclaimResult, "ChildReclaimed", {
+      parent: { u64: 1 },
+      childCollection: child.address,
+      childTokenId: { u64: 1 },
+    });
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("0,0");
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(dave.address);
   });
 
   it("Add child (same user) works", async () => {
@@ -242,6 +339,14 @@ describe("RMRK Nesting tests", () => {
     // dave adds child nft to his parent token2
     await addChild(child, parent, dave, 2);
 
+    // bob can't move the child out of dave's parent-2
+    const failTransferResult = await parent
+      .withSigner(bob)
+      .query.transferChild({ u64: 2 }, { u64: 1 }, [child.address, { u64: 1 }]);
+    expect(failTransferResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.NotTokenOwner()
+    );
+
     // dave transfers his child-1 from parent-2 to bob's parent-1, bob accepts the child
     const transferChildResult = await parent
       .withSigner(dave)
@@ -258,8 +363,15 @@ describe("RMRK Nesting tests", () => {
       (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
     ).to.be.equal("0,1");
 
+    // pending child has no parent, its root owner is the parent contract until accepted
+    const pendingOwner = (await child.query.directOwner({ u64: 1 })).value.unwrap().ok;
+    expect(pendingOwner[0]).to.equal(parent.address);
+    expect(pendingOwner[2]).to.equal(false);
+    expect((await child.query.rootOwner({ u64: 1 })).value.unwrap().ok).to.equal(parent.address);
+
     // bob accepts new child
     await acceptChild(child, parent, bob)
+    expect((await child.query.rootOwner({ u64: 1 })).value.unwrap().ok).to.equal(bob.address);
 
     // parent contract owns child token (in child contract)
     expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(
@@ -272,6 +384,441 @@ describe("RMRK Nesting tests", () => {
     // bob now owns child token (in child contract). Remember that Dave originally minted it.
     expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(bob.address);
   });
+
+  it("Burn parent with accepted child works", async () => {
+    // bob mints parent and child, and nests the child
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+    await addChild(child, parent, bob);
+
+    // bob nests a second child
+    await mintOne(child, bob, 2);
+    await child.withSigner(bob).tx.approve(parent.address, { u64: 2 }, true);
+    await parent.withSigner(bob).tx.addChild({ u64: 1 }, [child.address, { u64: 2 }]);
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("2,0");
+
+    // burning with budget lower than the number of children fails
+    const failBurnResult = await parent
+      .withSigner(bob)
+      .query.burn({ u64: 1 }, 1);
+    expect(failBurnResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.MaxRecursiveBurnsReached()
+    );
+
+    // bob burns parent, children are burned recursively
+    await parent.withSigner(bob).tx.burn({ u64: 1 }, 2);
+    expect(
+      (await parent.query.totalSupply()).value.unwrap().toNumber()
+    ).to.equal(0);
+    expect(
+      (await child.query.totalSupply()).value.unwrap().toNumber()
+    ).to.equal(0);
+  });
+
+  it("Burn parent fails when the budget runs out on a child with children", async () => {
+    // bob nests child-1 into parent-1, and parent-2 into child-1
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+    await addChild(child, parent, bob);
+    await mintOne(parent, bob, 2);
+    await parent.withSigner(bob).tx.approve(child.address, { u64: 2 }, true);
+    await child.withSigner(bob).tx.addChild({ u64: 1 }, [parent.address, { u64: 2 }]);
+    expect(
+      (await child.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("1,0");
+
+    // budget of 1 covers child-1 but not parent-2, nothing is burned or released
+    const failBurnResult = await parent
+      .withSigner(bob)
+      .query.burn({ u64: 1 }, 1);
+    expect(failBurnResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.MaxRecursiveBurnsReached()
+    );
+
+    // budget of 2 burns the whole tree
+    await parent.withSigner(bob).tx.burn({ u64: 1 }, 2);
+    expect(
+      (await parent.query.totalSupply()).value.unwrap().toNumber()
+    ).to.equal(0);
+    expect(
+      (await child.query.totalSupply()).value.unwrap().toNumber()
+    ).to.equal(0);
+  });
+
+  it("Burn and release parent releases accepted child", async () => {
+    // bob mints parent and child, and nests the child
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+    await addChild(child, parent, bob);
+
+    // dave can't burn bob's token
+    const failBurnResult = await parent
+      .withSigner(dave)
+      .query.burn({ u64: 1 }, 1);
+    expect(failBurnResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.NotTokenOwner());
+
+    // bob burns parent and releases the child to himself
+    await parent.withSigner(bob).tx.burnAndRelease({ u64: 1 });
+    expect(
+      (await parent.query.totalSupply()).value.unwrap().toNumber()
+    ).to.equal(0);
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(bob.address);
+  });
+
+  it("Burn parent returns pending child to its sender", async () => {
+    // dave adds a pending child to bob's parent
+    await mintOne(parent, bob);
+    await mintOne(child, dave);
+    await approve(child, parent, dave);
+    await addChild(child, parent, dave);
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("0,1");
+
+    // bob burns parent, pending child is returned to dave
+    await parent.withSigner(bob).tx.burn({ u64: 1 }, 1);
+    expect(
+      (await parent.query.totalSupply()).value.unwrap().toNumber()
+    ).to.equal(0);
+    expect(
+      (await child.query.totalSupply()).value.unwrap().toNumber()
+    ).to.equal(1);
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(dave.address);
+  });
+
+  it("Nest minted child is pending until the parent owner accepts it", async () => {
+    const minterFactory = new Minter_factory(api, deployer);
+    const minter = new Minter(
+      (
+        await minterFactory.new(
+          ["RmrkProject 3"],
+          ["RMKMINTER"],
+          [BASE_URI],
+          MAX_SUPPLY,
+          [COLLECTION_METADATA],
+        )
+      ).address,
+      deployer,
+      api
+    );
+
+    // deployer owns parent-1, bob owns parent-2
+    await mintOne(parent, deployer);
+    await mintOne(parent, bob, 2);
+
+    // minter collection reports deployer as the minter, the child is pending anyway
+    await minter.withSigner(deployer).tx.nestMint(parent.address, { u64: 1 }, { u64: 1 });
+    expect((await minter.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(parent.address);
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("0,1");
+    await parent.withSigner(deployer).tx.acceptChild({ u64: 1 }, [minter.address, { u64: 1 }]);
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("1,0");
+
+    // child minted into bob's parent is pending, rejected child goes back to the minter collection
+    await minter.withSigner(deployer).tx.nestMint(parent.address, { u64: 2 }, { u64: 2 });
+    expect(
+      (await parent.query.childrenBalance({ u64: 2 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("0,1");
+    await parent.withSigner(bob).tx.rejectChild({ u64: 2 }, [minter.address, { u64: 2 }]);
+    expect(
+      (await parent.query.childrenBalance({ u64: 2 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("0,0");
+    expect((await minter.query.ownerOf({ u64: 2 })).value.unwrap()).to.equal(minter.address);
+  });
+
+  it("Nesting limits work across collections", async () => {
+    // bob nests child-1 into parent-1
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+    await addChild(child, parent, bob);
+
+    // child collection allows only one level of nesting
+    await child.withSigner(deployer).tx.setNestingLimits(null, null, 1);
+    expect((await child.query.nestingLimits()).value.unwrap().toString()).to.equal(",,1");
+
+    // bob fails to nest parent-2 into child-1, which is already nested in parent-1
+    await mintOne(parent, bob, 2);
+    await parent.withSigner(bob).tx.approve(child.address, { u64: 2 }, true);
+    const failDepthResult = await child
+      .withSigner(bob)
+      .query.addChild({ u64: 1 }, [parent.address, { u64: 2 }]);
+    expect(failDepthResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.MaxNestingDepthReached()
+    );
+
+    // parent collection allows one accepted and one pending child per token
+    await parent.withSigner(deployer).tx.setNestingLimits(1, 1, null);
+    await mintOne(child, bob, 2);
+    await child.withSigner(bob).tx.approve(parent.address, { u64: 2 }, true);
+    const failAcceptedResult = await parent
+      .withSigner(bob)
+      .query.addChild({ u64: 1 }, [child.address, { u64: 2 }]);
+    expect(failAcceptedResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.MaxAcceptedChildrenReached()
+    );
+
+    // dave adds a pending child, the second one is rejected
+    await mintOne(child, dave, 3);
+    await child.withSigner(dave).tx.approve(parent.address, { u64: 3 }, true);
+    await parent.withSigner(dave).tx.addChild({ u64: 1 }, [child.address, { u64: 3 }]);
+    await mintOne(child, dave, 4);
+    await child.withSigner(dave).tx.approve(parent.address, { u64: 4 }, true);
+    const failPendingResult = await parent
+      .withSigner(dave)
+      .query.addChild({ u64: 1 }, [child.address, { u64: 4 }]);
+    expect(failPendingResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.MaxPendingChildrenReached()
+    );
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("1,1");
+  });
+
+  it("Nesting a parent into its own child fails", async () => {
+    // bob nests child-1 into parent-1
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+    await addChild(child, parent, bob);
+
+    // bob fails to nest parent-1 into child-1
+    await parent.withSigner(bob).tx.approve(child.address, { u64: 1 }, true);
+    const failCycleResult = await child
+      .withSigner(bob)
+      .query.addChild({ u64: 1 }, [parent.address, { u64: 1 }]);
+    expect(failCycleResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.NestingCycle());
+    expect((await parent.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(bob.address);
+  });
+
+  it("Only approved child collections can add children", async () => {
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+
+    // bob fails to add child-1, child collection is not approved
+    await parent.withSigner(deployer).tx.setAllowAnyChildCollection(false);
+    const failApprovalResult = await parent
+      .withSigner(bob)
+      .query.addChild({ u64: 1 }, [child.address, { u64: 1 }]);
+    expect(failApprovalResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.ChildContractNotApproved()
+    );
+
+    // child collection is approved with one child per parent token
+    await parent.withSigner(deployer).tx.approveChildCollection(child.address, 1);
+    expect((await parent.query.childCollection(child.address)).value.unwrap().toString())
+      .to.equal("true,1");
+    await addChild(child, parent, bob);
+
+    await mintOne(child, bob, 2);
+    await child.withSigner(bob).tx.approve(parent.address, { u64: 2 }, true);
+    const failLimitResult = await parent
+      .withSigner(bob)
+      .query.addChild({ u64: 1 }, [child.address, { u64: 2 }]);
+    expect(failLimitResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.MaxCollectionChildrenReached()
+    );
+  });
+
+  it("Transfer of a parent with a nested subtree works", async () => {
+    // bob nests child-1 into parent-1, and parent-2 into child-1
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+    await addChild(child, parent, bob);
+    await mintOne(parent, bob, 2);
+    await parent.withSigner(bob).tx.approve(child.address, { u64: 2 }, true);
+    await child.withSigner(bob).tx.addChild({ u64: 1 }, [parent.address, { u64: 2 }]);
+    expect((await parent.query.rootOwner({ u64: 2 })).value.unwrap().ok).to.equal(bob.address);
+
+    // dave adds pending child-2 to parent-1, and pending child-3 to the nested parent-2
+    await mintOne(child, dave, 2);
+    await child.withSigner(dave).tx.approve(parent.address, { u64: 2 }, true);
+    await parent.withSigner(dave).tx.addChild({ u64: 1 }, [child.address, { u64: 2 }]);
+    await mintOne(child, dave, 3);
+    await child.withSigner(dave).tx.approve(parent.address, { u64: 3 }, true);
+    await parent.withSigner(dave).tx.addChild({ u64: 2 }, [child.address, { u64: 3 }]);
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("1,1");
+    expect(
+      (await parent.query.childrenBalance({ u64: 2 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("0,1");
+
+    // bob transfers parent-1 to deployer
+    const transferGas = (
+      await parent
+        .withSigner(bob)
+        .query.transfer(deployer.address, { u64: 1 }, [])
+    ).gasRequired;
+    await parent
+      .withSigner(bob)
+      .tx.transfer(deployer.address, { u64: 1 }, [], { gasLimit: transferGas });
+
+    // pending child-2 is returned to dave, the accepted subtree follows parent-1
+    expect((await child.query.ownerOf({ u64: 2 })).value.unwrap()).to.equal(dave.address);
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("1,0");
+    expect((await child.query.rootOwner({ u64: 1 })).value.unwrap().ok).to.equal(
+      deployer.address
+    );
+    expect((await parent.query.rootOwner({ u64: 2 })).value.unwrap().ok).to.equal(
+      deployer.address
+    );
+
+    // pending child-3 of the nested parent-2 stays, only the new root owner accepts it
+    const failAcceptResult = await parent
+      .withSigner(bob)
+      .query.acceptChild({ u64: 2 }, [child.address, { u64: 3 }]);
+    expect(failAcceptResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.NotTokenOwner()
+    );
+    await parent.withSigner(deployer).tx.acceptChild({ u64: 2 }, [child.address, { u64: 3 }]);
+    expect((await child.query.rootOwner({ u64: 3 })).value.unwrap().ok).to.equal(
+      deployer.address
+    );
+  });
+
+  it("Transfer of a child to a parent in another collection works", async () => {
+    // bob nests child-1 into parent-1
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+    await addChild(child, parent, bob);
+    await acceptChild(child, parent, bob);
+
+    // bob owns a token in a third collection
+    const destination = new Rmrk(
+      (
+        await parentFactory.new(
+          ["RmrkProject 3"],
+          ["RMKDEST"],
+          [BASE_URI],
+          MAX_SUPPLY,
+          PRICE_PER_MINT,
+          [COLLECTION_METADATA],
+          ZERO_ADDRESS,
+          0
+        )
+      ).address,
+      deployer,
+      api
+    );
+    await mintOne(destination, bob);
+
+    // dave fails to move bob's child
+    const failResult = await parent
+      .withSigner(dave)
+      .query.transferChildToCollection({ u64: 1 }, destination.address, { u64: 1 }, [child.address, { u64: 1 }]);
+    expect(failResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.NotTokenOwner());
+
+    // bob moves child-1 to destination-1 in one transaction
+    const transferResult = await parent
+      .withSigner(bob)
+      .tx.transferChildToCollection({ u64: 1 }, destination.address, { u64: 1 }, [child.address, { u64: 1 }]);
+    emit(transferResult, "ChildRemoved", {
+      parent: { u64: 1 },
+      childCollection: child.address,
+      childTokenId: { u64: 1 },
+    });
+
+    // child lands as accepted, both parents are owned by bob
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(destination.address);
+    expect(
+      (await parent.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("0,0");
+    expect(
+      (await destination.query.childrenBalance({ u64: 1 }))?.value.unwrap().ok.toString()
+    ).to.be.equal("1,0");
+    expect((await child.query.rootOwner({ u64: 1 })).value.unwrap().ok).to.equal(bob.address);
+  });
+
+  it("Root owner of a nested child works", async () => {
+    // bob nests child-1 into parent-1
+    await mintOne(parent, bob);
+    await mintOne(child, bob);
+    await approve(child, parent, bob);
+    await addChild(child, parent, bob);
+
+    const directOwner = (await child.query.directOwner({ u64: 1 })).value.unwrap().ok;
+    expect(directOwner[0]).to.equal(parent.address);
+    expect(directOwner[1].u64).to.equal(1);
+    expect(directOwner[2]).to.equal(true);
+    expect((await child.query.rootOwner({ u64: 1 })).value.unwrap().ok).to.equal(bob.address);
+
+    // deployer adds an asset to child-1, it is pending since child-1 is owned by parent contract
+    await child.withSigner(deployer).tx.addAssetEntry(null, 1, 0, ["ipfs://child/1.svg"], []);
+    await child.withSigner(deployer).tx.addAssetToToken({ u64: 1 }, 1, null);
+
+    // dave fails to accept the asset, bob accepts it as root owner
+    const failAcceptResult = await child
+      .withSigner(dave)
+      .query.acceptAsset({ u64: 1 }, 1);
+    expect(failAcceptResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.NotTokenOwner());
+    const acceptAssetResult = await child.withSigner(bob).tx.acceptAsset({ u64: 1 }, 1);
+    emit(acceptAssetResult, "AssetAccepted", { token: { u64: 1 }, asset: 1 });
+  });
+
+  it("Soulbound child only moves in and out of parents of its root owner", async () => {
+    // bob owns parent-1 and a locked child-1, dave owns parent-2
+    await mintOne(parent, bob);
+    await mintOne(parent, dave, 2);
+    await child.withSigner(deployer).tx.lockToken({ u64: 1 });
+    await mintOne(child, bob);
+
+    // minted tokens can't be locked
+    const lockResult = await child.withSigner(deployer).query.lockToken({ u64: 1 });
+    expect(lockResult.value.unwrap().err).to.not.be.undefined;
+
+    // bob can't transfer the locked child to dave
+    const transferResult = await child.withSigner(bob).query.transfer(dave.address, { u64: 1 }, []);
+    expect(transferResult.value.unwrap().err).to.not.be.undefined;
+
+    // locked child moves only into collections approved by the child collection
+    await approve(child, parent, bob);
+    const addToUnapprovedResult = await parent
+      .withSigner(bob)
+      .query.addChild({ u64: 1 }, [child.address, { u64: 1 }]);
+    expect(addToUnapprovedResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.CannotTransferSoulbound()
+    );
+    await child.withSigner(deployer).tx.approveChildCollection(parent.address, null);
+
+    // bob can't nest the locked child into dave's parent-2
+    const addToDaveResult = await parent
+      .withSigner(bob)
+      .query.addChild({ u64: 2 }, [child.address, { u64: 1 }]);
+    expect(addToDaveResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.CannotTransferSoulbound()
+    );
+
+    // bob nests the locked child into his own parent-1
+    await addChild(child, parent, bob);
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(parent.address);
+    expect((await child.query.rootOwner({ u64: 1 })).value.unwrap().ok).to.equal(bob.address);
+
+    // bob can't move the child to dave's parent-2
+    const transferChildResult = await parent
+      .withSigner(bob)
+      .query.transferChild({ u64: 1 }, { u64: 2 }, [child.address, { u64: 1 }]);
+    expect(transferChildResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.CannotTransferSoulbound()
+    );
+
+    // bob takes the child back out of his parent
+    await removeChild(child, parent, bob);
+    expect((await child.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(bob.address);
+  });
 });
 
 
@@ -7,7 +7,9 @@ import { KeyringPair } from "@polkadot/keyring/types";
 import BN from "bn.js";
 import Rmrk_factory from "../types/constructors/rmrk_example_equippable_lazy";
 import Rmrk from "../types/contracts/rmrk_example_equippable_lazy";
-import { RmrkError } from "../types/types-returns/rmrk_example_equippable_lazy";
+import { RmrkErrorBuilder } from "../types/types-returns/rmrk_example_equippable_lazy";
+import Token_factory from "../types/constructors/rmrk_example_psp22";
+import Token from "../types/contracts/rmrk_example_psp22";
 import { emit } from "./helper";
 
 import { SignAndSendSuccessResponse } from "@727-ventures/typechain-types";
@@ -192,7 +194,157 @@ describe("Minting rmrk as psp34, using MintingLazy trait from rmrk_example_equip
         value: 0
       },
     );
-    expect(mintResult.value.unwrap().err.rmrk).to.be.equal(RmrkError.badMintValue);
+    expect(mintResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.BadMintValue());
+  });
+
+  it("royalty info works", async () => {
+    const SALE_PRICE = 10_000;
+
+    // No royalty is set on deployment
+    let royaltyInfo = (await contract.query.royaltyInfo({ u64: 1 }, SALE_PRICE)).value.unwrap();
+    expect(royaltyInfo[0]).to.equal(ZERO_ADDRESS);
+    expect(royaltyInfo[1].toNumber()).to.equal(0);
+
+    // Only admin can set royalty
+    const failResult = await contract.withSigner(bob).query.setRoyalty(bob.address, 500);
+    expect(failResult.value.unwrap().err).to.not.be.undefined;
+
+    // Royalty can't exceed 100%
+    const tooHighResult = await contract.query.setRoyalty(bob.address, 10_001);
+    expect(tooHighResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.RoyaltyTooHigh());
+
+    // Collection royalty of 5%
+    await contract.tx.setRoyalty(bob.address, 500);
+    royaltyInfo = (await contract.query.royaltyInfo({ u64: 1 }, SALE_PRICE)).value.unwrap();
+    expect(royaltyInfo[0]).to.equal(bob.address);
+    expect(royaltyInfo[1].toNumber()).to.equal(500);
+
+    // Token royalty override of 10%
+    await contract.tx.setTokenRoyalty({ u64: 2 }, deployer.address, 1_000);
+    royaltyInfo = (await contract.query.royaltyInfo({ u64: 2 }, SALE_PRICE)).value.unwrap();
+    expect(royaltyInfo[0]).to.equal(deployer.address);
+    expect(royaltyInfo[1].toNumber()).to.equal(1_000);
+
+    // Resetting the override falls back to the collection royalty
+    await contract.tx.resetTokenRoyalty({ u64: 2 });
+    royaltyInfo = (await contract.query.royaltyInfo({ u64: 2 }, SALE_PRICE)).value.unwrap();
+    expect(royaltyInfo[0]).to.equal(bob.address);
+  });
+
+  it("payment splitter works", async () => {
+    // Only admin can set payees
+    const failResult = await contract.withSigner(bob).query.setPayees([[bob.address, 1]]);
+    expect(failResult.value.unwrap().err).to.not.be.undefined;
+
+    // Deployer gets 1/4 and Bob gets 3/4 of the revenue
+    const setPayeesResult = await contract.tx.setPayees([[deployer.address, 1], [bob.address, 3]]);
+    emit(setPayeesResult, "PayeeAdded", {
+      account: bob.address,
+      shares: 3,
+    });
+    expect((await contract.query.totalShares()).value.unwrap().toNumber()).to.equal(4);
+
+    await mintOne(contract, bob);
+    expect(
+      (await contract.query.pendingPayment(bob.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.muln(3).divn(4).toString());
+    expect(
+      (await contract.query.pendingPayment(deployer.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.divn(4).toString());
+
+    // Anyone can release payment to the payee
+    const releaseResult = await contract.withSigner(bob).tx.release(deployer.address);
+    emit(releaseResult, "PaymentReleased", {
+      to: deployer.address,
+      amount: PRICE_PER_MINT.divn(4),
+    });
+    expect(
+      (await contract.query.released(deployer.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.divn(4).toString());
+    expect(
+      (await contract.query.pendingPayment(deployer.address)).value.unwrap().toString()
+    ).to.equal("0");
+
+    // Nothing left to release
+    const noPaymentResult = await contract.query.release(deployer.address);
+    expect(noPaymentResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.NoPaymentDue());
+
+    // Payment due to Bob stays owed to him after the payees are replaced
+    await contract.tx.setPayees([[deployer.address, 1]]);
+    expect((await contract.query.totalReceived()).value.unwrap().toString()).to.equal("0");
+    expect(
+      (await contract.query.pendingPayment(bob.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.muln(3).divn(4).toString());
+    const owedResult = await contract.tx.release(bob.address);
+    emit(owedResult, "PaymentReleased", {
+      to: bob.address,
+      amount: PRICE_PER_MINT.muln(3).divn(4),
+    });
+    expect(
+      (await contract.query.pendingPayment(bob.address)).value.unwrap().toString()
+    ).to.equal("0");
+  });
+
+  it("mint paid in PSP22 token works", async () => {
+    const tokenFactory = new Token_factory(api, deployer);
+    const token = new Token(
+      (await tokenFactory.new(PRICE_PER_MINT.muln(10))).address,
+      deployer,
+      api
+    );
+    await token.tx.transfer(bob.address, PRICE_PER_MINT.muln(2), []);
+
+    // Only admin can set the payment token
+    const failResult = await contract.withSigner(bob).query.setPaymentToken(token.address);
+    expect(failResult.value.unwrap().err).to.not.be.undefined;
+    await contract.tx.setPaymentToken(token.address);
+    expect((await contract.query.paymentToken()).value.unwrap()).to.equal(token.address);
+
+    // Native value is not accepted once the payment token is set
+    const nativeResult = await contract.withSigner(bob).query.mint({ value: PRICE_PER_MINT });
+    expect(nativeResult.value.unwrap().err.rmrk).to.deep.equal(RmrkErrorBuilder.BadMintValue());
+
+    // Minting without allowance fails
+    const noAllowanceResult = await contract.withSigner(bob).query.mint();
+    expect(noAllowanceResult.value.unwrap().err.rmrk).to.deep.equal(
+      RmrkErrorBuilder.PaymentTransferFailed()
+    );
+
+    // Deployer gets 1/4 and Bob gets 3/4 of the revenue
+    await contract.tx.setPayees([[deployer.address, 1], [bob.address, 3]]);
+
+    // Bob pays for one token with PSP22
+    await token.withSigner(bob).tx.approve(contract.address, PRICE_PER_MINT);
+    await contract.withSigner(bob).tx.mint();
+    expect((await contract.query.ownerOf({ u64: 1 })).value.unwrap()).to.equal(bob.address);
+    expect(
+      (await token.query.balanceOf(bob.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.toString());
+    expect(
+      (await token.query.balanceOf(contract.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.toString());
+
+    // PSP22 revenue is released to each payee separately
+    expect(
+      (await contract.query.psp22TotalReceived(token.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.toString());
+    const deployerBalance = (await token.query.balanceOf(deployer.address)).value.unwrap().rawNumber;
+    const releaseResult = await contract.withSigner(bob).tx.releasePsp22(token.address, bob.address);
+    emit(releaseResult, "Psp22PaymentReleased", {
+      token: token.address,
+      to: bob.address,
+      amount: PRICE_PER_MINT.muln(3).divn(4),
+    });
+    expect(
+      (await token.query.balanceOf(bob.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.muln(7).divn(4).toString());
+    expect(
+      (await contract.query.psp22PendingPayment(token.address, deployer.address)).value.unwrap().toString()
+    ).to.equal(PRICE_PER_MINT.divn(4).toString());
+    await contract.tx.releasePsp22(token.address, deployer.address);
+    expect(
+      (await token.query.balanceOf(deployer.address)).value.unwrap().toString()
+    ).to.equal(deployerBalance.add(PRICE_PER_MINT.divn(4)).toString());
   });
 });
 